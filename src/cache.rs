@@ -23,6 +23,16 @@ static REQWEST_CLIENT: tokio::sync::OnceCell<reqwest::Client> = tokio::sync::Onc
 
 pub async fn get_or_init_reqwest_client() -> Result<&'static reqwest::Client, crate::error::Error> {
     REQWEST_CLIENT
-        .get_or_try_init(|| async { Ok(reqwest::Client::new()) })
+        .get_or_try_init(|| async {
+            let client = reqwest::Client::builder()
+                .user_agent(concat!("mcp-rust-docs/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .map_err(|e| {
+                    tracing::error!("{}", e);
+                    crate::error::Error::InitializeClient(e.to_string())
+                })?;
+
+            Ok(client)
+        })
         .await
 }
@@ -0,0 +1,222 @@
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub transport: TransportConfig,
+    pub http_cache: HttpCacheConfig,
+    pub search_index: SearchIndexConfig,
+    pub local_docs: LocalDocsConfig,
+    pub std_docs: StdDocsConfig,
+    pub workspace: WorkspaceConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransportConfig {
+    pub kind: TransportKind,
+
+    /// Address the HTTP transports listen on. Ignored for `stdio`.
+    pub bind: std::net::SocketAddr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+    /// Serve a single client over stdin/stdout.
+    Stdio,
+
+    /// Serve MCP streamable HTTP on `/mcp`.
+    StreamableHttp,
+
+    /// Serve the legacy SSE transport on `/sse` and `/message`.
+    Sse,
+}
+
+impl std::str::FromStr for TransportKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stdio" => Ok(Self::Stdio),
+            "streamable-http" | "http" => Ok(Self::StreamableHttp),
+            "sse" => Ok(Self::Sse),
+            _ => Err(format!("Unknown transport: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpCacheConfig {
+    /// Disables the on-disk HTTP cache when `false`.
+    pub enabled: bool,
+
+    /// Directory where cached responses are stored.
+    pub dir: std::path::PathBuf,
+
+    /// How long responses for mutable URLs (e.g. `latest`) are considered fresh.
+    /// Responses for concrete versions never expire.
+    pub ttl: std::time::Duration,
+
+    /// Upper bound of the total size of cached responses in bytes.
+    pub max_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchIndexConfig {
+    /// Persists indexes of concrete crate versions on disk when `true`.
+    pub persistent: bool,
+
+    /// Directory where persistent indexes are stored.
+    pub dir: std::path::PathBuf,
+
+    /// Maximum number of indexes kept open in memory.
+    pub capacity: usize,
+
+    /// How long indexes of mutable versions (e.g. `latest`) are reused.
+    pub ttl: std::time::Duration,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LocalDocsConfig {
+    /// `target/doc` directory produced by `cargo doc`.
+    pub dir: Option<std::path::PathBuf>,
+
+    /// Crates that are always read from `dir` instead of docs.rs, e.g. private workspace crates.
+    pub crates: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StdDocsConfig {
+    /// `share/doc/rust/html` directory of the installed toolchain, if `rust-docs` is installed.
+    pub toolchain_dir: Option<std::path::PathBuf>,
+
+    /// Reads `std`, `core`, `alloc` and `proc_macro` from `toolchain_dir` instead of
+    /// doc.rust-lang.org unless a call asks for another source.
+    pub prefer_toolchain: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceConfig {
+    /// Cargo workspace or package whose `Cargo.lock` pins the versions of documentation
+    /// requested without a version.
+    pub dir: Option<std::path::PathBuf>,
+}
+
+impl Config {
+    /// Builds the configuration from `MCP_RUST_DOCS_*` environment variables.
+    pub fn from_env() -> Self {
+        let cache_dir = std::env::var_os("MCP_RUST_DOCS_CACHE_DIR")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(default_cache_dir);
+
+        let http_cache = HttpCacheConfig {
+            enabled: env_parse("MCP_RUST_DOCS_HTTP_CACHE", true),
+            dir: cache_dir.join("http"),
+            ttl: std::time::Duration::from_secs(env_parse(
+                "MCP_RUST_DOCS_HTTP_CACHE_TTL_SECS",
+                3600,
+            )),
+            max_bytes: env_parse("MCP_RUST_DOCS_HTTP_CACHE_MAX_BYTES", 512 * 1024 * 1024),
+        };
+
+        let search_index = SearchIndexConfig {
+            persistent: env_parse("MCP_RUST_DOCS_INDEX_CACHE", true),
+            dir: cache_dir.join("index"),
+            capacity: env_parse("MCP_RUST_DOCS_INDEX_CACHE_CAPACITY", 32),
+            ttl: http_cache.ttl,
+        };
+
+        let transport = TransportConfig {
+            kind: env_parse("MCP_RUST_DOCS_TRANSPORT", TransportKind::Stdio),
+            bind: env_parse(
+                "MCP_RUST_DOCS_BIND",
+                std::net::SocketAddr::from(([127, 0, 0, 1], 8080)),
+            ),
+        };
+
+        let local_docs = LocalDocsConfig {
+            dir: std::env::var_os("MCP_RUST_DOCS_LOCAL_DOC_DIR").map(std::path::PathBuf::from),
+            crates: std::env::var("MCP_RUST_DOCS_LOCAL_CRATES")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        };
+
+        let std_docs = StdDocsConfig {
+            toolchain_dir: std::env::var_os("MCP_RUST_DOCS_TOOLCHAIN_DOC_DIR")
+                .map(std::path::PathBuf::from)
+                .or_else(toolchain_doc_dir),
+            prefer_toolchain: env_parse("MCP_RUST_DOCS_STD_FROM_TOOLCHAIN", false),
+        };
+
+        let workspace = WorkspaceConfig {
+            dir: std::env::var_os("MCP_RUST_DOCS_WORKSPACE").map(std::path::PathBuf::from),
+        };
+
+        Self {
+            transport,
+            http_cache,
+            search_index,
+            local_docs,
+            std_docs,
+            workspace,
+        }
+    }
+
+    /// Applies command line arguments on top of the environment.
+    /// `--workspace <path>` (or a bare path) sets the workspace directory.
+    pub fn with_args(mut self, args: impl IntoIterator<Item = std::ffi::OsString>) -> Self {
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let dir = match arg.to_str() {
+                Some("--workspace") => args.next(),
+                Some(arg) if arg.starts_with("--workspace=") => {
+                    Some(arg.trim_start_matches("--workspace=").into())
+                }
+                Some(arg) if arg.starts_with('-') => {
+                    tracing::warn!("Ignoring unknown argument: {arg}");
+                    None
+                }
+                _ => Some(arg),
+            };
+
+            if let Some(dir) = dir {
+                self.workspace.dir = Some(std::path::PathBuf::from(dir));
+            }
+        }
+
+        self
+    }
+}
+
+fn default_cache_dir() -> std::path::PathBuf {
+    std::env::var_os("XDG_CACHE_HOME")
+        .map(std::path::PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| std::path::Path::new(&home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir)
+        .join("mcp-rust-docs")
+}
+
+/// Locates the HTML docs the `rust-docs` rustup component installs into the active toolchain.
+fn toolchain_doc_dir() -> Option<std::path::PathBuf> {
+    let output = std::process::Command::new("rustc")
+        .args(["--print", "sysroot"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let sysroot = String::from_utf8(output.stdout).ok()?;
+    let dir = std::path::Path::new(sysroot.trim()).join("share/doc/rust/html");
+
+    dir.is_dir().then_some(dir)
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str, default: T) -> T {
+    match std::env::var(key) {
+        Ok(value) => value.parse::<T>().unwrap_or_else(|_| {
+            tracing::warn!("Ignoring invalid value for {}: {}", key, value);
+            default
+        }),
+        Err(_) => default,
+    }
+}
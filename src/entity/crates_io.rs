@@ -8,3 +8,115 @@ pub struct CrateSummaryEntity {
     pub created_at: String,
     pub updated_at: String,
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct CrateSearchEntity {
+    /// Total number of matching crates across all pages.
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+    /// Page to request next, or `None` on the last page.
+    pub next_page: Option<u64>,
+    pub crates: Vec<CrateSummaryEntity>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CrateDetailEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub repository: Option<String>,
+    pub homepage: Option<String>,
+    pub documentation: Option<String>,
+    pub license: Option<String>,
+    pub keywords: Vec<String>,
+    pub categories: Vec<String>,
+    /// Minimum supported Rust version (`rust-version`) of the latest version.
+    pub rust_version: Option<String>,
+    pub edition: Option<String>,
+    pub owners: Vec<CrateOwnerEntity>,
+    pub downloads: u64,
+    pub recent_downloads: Option<u64>,
+    pub latest_stable_version: Option<String>,
+    pub latest_version: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub versions: Vec<CrateVersionEntity>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CrateOwnerEntity {
+    pub login: String,
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CrateVersionEntity {
+    pub num: String,
+    pub yanked: bool,
+    pub created_at: String,
+    pub downloads: u64,
+    pub license: Option<String>,
+    pub rust_version: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CrateDependenciesEntity {
+    pub name: String,
+    pub version: String,
+    pub dependencies: Vec<DependencyEntity>,
+    pub features: Vec<FeatureEntity>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DependencyEntity {
+    pub name: String,
+    pub req: String,
+    /// `normal`, `dev` or `build`
+    pub kind: String,
+    pub optional: bool,
+    pub default_features: bool,
+    pub features: Vec<String>,
+    /// Platform the dependency is restricted to, e.g. `cfg(windows)`.
+    pub target: Option<String>,
+    /// Features of this crate that turn this optional dependency on.
+    pub enabled_by_features: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct FeatureEntity {
+    pub name: String,
+    /// Raw feature values as declared in `Cargo.toml`, e.g. `dep:serde` or `tokio/rt`.
+    pub enables: Vec<String>,
+    /// Optional dependencies this feature turns on, directly or through other features.
+    pub optional_dependencies: Vec<String>,
+    /// Whether the feature is enabled by `default`.
+    pub default: bool,
+    /// Whether this is the implicit feature Cargo creates for an optional dependency.
+    pub implicit: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReverseDependenciesEntity {
+    pub name: String,
+    /// Total number of dependent crates across all pages.
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+    pub dependents: Vec<ReverseDependencyEntity>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReverseDependencyEntity {
+    pub crate_name: String,
+    /// Latest version of the dependent crate that depends on the queried crate.
+    pub version: String,
+    /// Version requirement the dependent crate declares on the queried crate.
+    pub req: String,
+    /// `normal`, `dev` or `build`
+    pub kind: String,
+    pub optional: bool,
+    /// Downloads of `version`.
+    pub downloads: u64,
+}
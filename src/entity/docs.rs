@@ -1,6 +1,244 @@
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct Item {
     pub r#type: String,
     pub href: Option<String>,
     pub path: Option<String>,
+
+    /// rustdoc item kind such as `struct` or `function`. Available from rustdoc JSON and
+    /// `search-index.js`, but not from `all.html`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    /// Fully-qualified path including the crate name, e.g. `serde::de::Deserialize`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+
+    /// First paragraph of the item's documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+
+    /// `stable` or `unstable` when the item carries a stability attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stability: Option<String>,
+}
+
+/// An item found by a ranked search, with its relevance.
+#[derive(Debug, serde::Serialize)]
+pub struct SearchHit {
+    pub score: f32,
+
+    #[serde(flatten)]
+    pub item: Item,
+}
+
+/// An item found by a search across several crates.
+#[derive(Debug, serde::Serialize)]
+pub struct CrateSearchHit {
+    pub crate_name: String,
+    pub version: String,
+
+    #[serde(flatten)]
+    pub hit: SearchHit,
+}
+
+/// A documentation page found by a full-text search.
+#[derive(Debug, serde::Serialize)]
+pub struct TextSearchHit {
+    pub score: f32,
+    /// Path of the documented item, or the crate name for the crate root.
+    pub path: String,
+    /// Page to pass as `path` to `retrieve_documentation_page`, e.g. `/sync/mpsc/index.html`.
+    pub href: String,
+    /// Best matching passage, with the matched words in bold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Public API changes between two versions of a crate.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct ApiDiff {
+    pub added: Vec<Item>,
+    pub removed: Vec<Item>,
+
+    /// Removed items that look like they were renamed or moved to one of the added items.
+    pub renamed: Vec<RenamedItem>,
+
+    /// Items whose signature differs. Only compared when both versions provide signatures.
+    pub signature_changed: Vec<SignatureChange>,
+
+    /// Items that are deprecated in the new version but were not in the old one.
+    pub deprecated: Vec<Item>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RenamedItem {
+    pub old: Item,
+    pub new: Item,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SignatureChange {
+    pub r#type: String,
+    pub path: String,
+    pub old_signature: String,
+    pub new_signature: String,
+}
+
+/// Release notes of a crate, taken from its changelog.
+#[derive(Debug, serde::Serialize)]
+pub struct Changelog {
+    /// File inside the packaged crate or URL the changelog was read from.
+    pub source: String,
+    /// Newest first, as in the changelog.
+    pub sections: Vec<ChangelogSection>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ChangelogSection {
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Markdown of the section without its heading.
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct Deprecation {
+    pub since: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Concrete version a requested version (`latest`, `1.2.3`, `^1.2`, ...) was resolved to.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedVersion {
+    pub crate_name: String,
+    pub requested_version: String,
+    pub resolved_version: String,
+    pub source: DocsSource,
+}
+
+/// Where documentation pages are read from.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    rmcp::schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DocsSource {
+    /// Published builds on docs.rs.
+    #[default]
+    DocsRs,
+
+    /// Standard library documentation on doc.rust-lang.org.
+    RustLang,
+
+    /// Standard library documentation installed with the local rustup toolchain (`rust-docs`).
+    Toolchain,
+
+    /// `cargo doc` output in the configured local `target/doc` directory.
+    Local,
+}
+
+/// Documentation page a fully-qualified Rust path was resolved to.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ItemLocation {
+    /// Rust path as requested, e.g. `tokio::sync::mpsc::Sender::send`.
+    pub rust_path: String,
+
+    /// Page path for `retrieve_documentation_page`, e.g. `/sync/mpsc/struct.Sender.html`.
+    pub path: String,
+
+    /// Kind of the item the page documents, e.g. `struct` or `module`.
+    pub kind: Option<String>,
+
+    /// Member name when the path names a method, variant or field of the item, e.g. `send`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<String>,
+}
+
+/// Position of a returned chunk within a paginated documentation page.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PageChunk {
+    /// Byte offset of the chunk within the whole page.
+    pub offset: usize,
+    pub length: usize,
+    pub total_length: usize,
+
+    /// Pass as `cursor` to retrieve the next chunk. `None` on the last chunk.
+    pub next_cursor: Option<String>,
+}
+
+/// Parts of a rustdoc item page (struct, enum, trait, function, ...).
+/// Parts that were not requested or are not present on the page are omitted.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct ItemPage {
+    /// Page heading, e.g. `Struct Vec`.
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+
+    /// Top-level documentation as markdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
+
+    /// Code of the examples in the top-level documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<String>>,
+
+    /// Inherent methods, or the required and provided methods of a trait.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<ItemMember>>,
+
+    /// Headers of the trait implementations, e.g. `impl<T> Clone for Vec<T>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trait_implementations: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_trait_implementations: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blanket_implementations: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct ItemMember {
+    pub name: String,
+
+    /// Anchor of the member on the item page, e.g. `method.retain`.
+    pub anchor: String,
+
+    pub signature: String,
+
+    /// First paragraph of the member's documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Full documentation as markdown. Only filled when a single member is retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, rmcp::schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemPageSection {
+    Declaration,
+    Docs,
+    Examples,
+    Methods,
+    TraitImplementations,
+    AutoTraitImplementations,
+    BlanketImplementations,
 }
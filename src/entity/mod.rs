@@ -1,2 +1,3 @@
 pub mod crates_io;
 pub mod docs;
+pub mod workspace;
@@ -0,0 +1,21 @@
+/// A crate the configured workspace depends on.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct WorkspaceDependency {
+    pub name: String,
+
+    /// Version locked in `Cargo.lock`, or the version requirement of `Cargo.toml`
+    /// when the workspace has no lockfile.
+    pub version: String,
+
+    /// Whether `version` comes from `Cargo.lock`.
+    pub locked: bool,
+
+    /// Whether a workspace member depends on the crate itself rather than only through
+    /// other dependencies.
+    pub direct: bool,
+
+    /// Registry or git repository the crate comes from, e.g.
+    /// `registry+https://github.com/rust-lang/crates.io-index`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
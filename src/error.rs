@@ -15,6 +15,39 @@ pub enum Error {
     #[error("Failed to parse HTML: {0}")]
     HtmlMainContentNotFound(String),
 
+    #[error("Failed to resolve version: {0}")]
+    VersionResolution(String),
+
+    #[error("Failed to read rustdoc JSON: {0}")]
+    RustdocJson(String),
+
+    #[error("Failed to read rustdoc search index: {0}")]
+    SearchIndexJs(String),
+
+    #[error("Failed to read local documentation: {0}")]
+    LocalDocs(String),
+
+    #[error("Item not found: {0}")]
+    ItemNotFound(String),
+
+    #[error("Ambiguous item path: {0}")]
+    AmbiguousItemPath(String),
+
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(String),
+
+    #[error("Invalid signature query: {0}")]
+    InvalidSignatureQuery(String),
+
+    #[error("Search task failed: {0}")]
+    SearchTask(String),
+
+    #[error("Failed to read changelog: {0}")]
+    Changelog(String),
+
+    #[error("Failed to read workspace: {0}")]
+    Workspace(String),
+
     #[error("Failed to create temporary directories.")]
     CreateTempDir(String),
 
@@ -2,6 +2,7 @@
 pub struct Handler {
     pub crates_io_use_case: crate::use_case::crates_io::CratesIoUseCase,
     pub docs_use_case: crate::use_case::docs::DocsUseCase,
+    pub workspace_use_case: crate::use_case::workspace::WorkspaceUseCase,
     pub tool_router: rmcp::handler::server::tool::ToolRouter<Self>,
     pub resource_map: crate::resource::ResourceMap,
 }
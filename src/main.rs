@@ -1,4 +1,5 @@
 pub mod cache;
+pub mod config;
 pub mod entity;
 pub mod error;
 pub mod handler;
@@ -6,26 +7,56 @@ pub mod record;
 pub mod repository;
 pub mod resource;
 pub mod tool;
+pub mod transport;
 pub mod use_case;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = crate::config::Config::from_env().with_args(std::env::args_os().skip(1));
+
     let crates_io_repository =
         std::sync::Arc::new(crate::repository::crates_io::CratesIoRepositoryImpl {});
     let crates_io_use_case = crate::use_case::crates_io::CratesIoUseCase {
-        crates_io_repository,
+        crates_io_repository: crates_io_repository.clone(),
     };
 
-    let http_repository = std::sync::Arc::new(crate::repository::http::HttpRepositoryImpl {});
-    let http_use_case = crate::use_case::docs::DocsUseCase { http_repository };
-
-    use rmcp::ServiceExt;
+    let http_repository: std::sync::Arc<dyn crate::repository::http::HttpRepository + Send + Sync> =
+        std::sync::Arc::new(crate::repository::http::HttpRepositoryImpl {});
+    let http_repository = if config.http_cache.enabled {
+        std::sync::Arc::new(crate::repository::http::CachedHttpRepositoryImpl::new(
+            http_repository,
+            &config.http_cache,
+        ))
+    } else {
+        http_repository
+    };
+    let search_index_cache = std::sync::Arc::new(
+        crate::use_case::search_index::SearchIndexCache::new(&config.search_index),
+    );
+    let text_index_cache = std::sync::Arc::new(
+        crate::use_case::search_index::SearchIndexCache::new(&config.search_index),
+    );
+    let file_repository = std::sync::Arc::new(crate::repository::file::FileRepositoryImpl {});
+    let workspace_use_case = crate::use_case::workspace::WorkspaceUseCase {
+        file_repository: file_repository.clone(),
+        workspace: config.workspace.clone(),
+        dependency_cache: Default::default(),
+    };
+    let http_use_case = crate::use_case::docs::DocsUseCase {
+        http_repository,
+        file_repository,
+        crates_io_repository,
+        search_index_cache,
+        text_index_cache,
+        version_cache: Default::default(),
+        local_docs: config.local_docs.clone(),
+        std_docs: config.std_docs.clone(),
+    };
 
-    let tool = crate::handler::Handler::new(crates_io_use_case, http_use_case)
-        .serve(rmcp::transport::stdio())
-        .await?;
+    let handler =
+        crate::handler::Handler::new(crates_io_use_case, http_use_case, workspace_use_case);
 
-    tool.waiting().await?;
+    crate::transport::serve(handler, &config.transport).await?;
 
     Ok(())
 }
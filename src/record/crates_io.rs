@@ -8,3 +8,110 @@ pub struct CrateRecord {
     pub created_at: String,
     pub updated_at: String,
 }
+
+#[derive(Debug, Default)]
+pub struct CrateSearchRecord {
+    /// Total number of crates matching the query across all pages.
+    pub total: u64,
+    pub crates: Vec<CrateRecord>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CrateSearchQuery {
+    pub search: String,
+    pub page: u64,
+    pub per_page: u64,
+    pub sort: CrateSort,
+    /// crates.io category slug, e.g. `command-line-utilities`.
+    pub category: Option<String>,
+    /// crates.io keyword (tag), e.g. `async`.
+    pub keyword: Option<String>,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, rmcp::schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CrateSort {
+    #[default]
+    Relevance,
+    Downloads,
+    RecentDownloads,
+    RecentlyUpdated,
+    NewlyAdded,
+    Alphabetical,
+}
+
+#[derive(Debug, Default)]
+pub struct VersionRecord {
+    pub num: String,
+    pub yanked: bool,
+    pub created_at: String,
+    pub downloads: u64,
+    pub license: Option<String>,
+    pub rust_version: Option<String>,
+    pub features: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default)]
+pub struct OwnerRecord {
+    pub login: String,
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Default)]
+pub struct CrateDetailRecord {
+    pub name: String,
+    pub description: Option<String>,
+    pub repository: Option<String>,
+    pub homepage: Option<String>,
+    pub documentation: Option<String>,
+    pub license: Option<String>,
+    pub keywords: Vec<String>,
+    pub categories: Vec<String>,
+    pub rust_version: Option<String>,
+    pub edition: Option<String>,
+    pub owners: Vec<OwnerRecord>,
+    pub downloads: u64,
+    pub recent_downloads: Option<u64>,
+    pub latest_stable_version: Option<String>,
+    pub latest_version: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub versions: Vec<VersionRecord>,
+}
+
+#[derive(Debug, Default)]
+pub struct DependencyRecord {
+    pub name: String,
+    pub req: String,
+    pub kind: String,
+    pub optional: bool,
+    pub default_features: bool,
+    pub features: Vec<String>,
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct CrateDependenciesRecord {
+    pub dependencies: Vec<DependencyRecord>,
+    pub features: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default)]
+pub struct ReverseDependencyRecord {
+    pub crate_name: String,
+    pub version: String,
+    pub req: String,
+    pub kind: String,
+    pub optional: bool,
+    pub downloads: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct ReverseDependenciesRecord {
+    pub total: u64,
+    pub dependencies: Vec<ReverseDependencyRecord>,
+}
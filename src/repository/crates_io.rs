@@ -2,34 +2,173 @@
 pub trait CratesIoRepository: std::fmt::Debug + Send + Sync {
     async fn search_crate(
         &self,
-        keyword: &str,
-    ) -> Result<Vec<crate::record::crates_io::CrateRecord>, crate::error::Error>;
+        query: &crate::record::crates_io::CrateSearchQuery,
+    ) -> Result<crate::record::crates_io::CrateSearchRecord, crate::error::Error>;
+
+    async fn list_versions(
+        &self,
+        crate_name: &str,
+    ) -> Result<Vec<crate::record::crates_io::VersionRecord>, crate::error::Error>;
+
+    async fn get_crate(
+        &self,
+        crate_name: &str,
+    ) -> Result<crate::record::crates_io::CrateDetailRecord, crate::error::Error>;
+
+    async fn get_dependencies(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<crate::record::crates_io::CrateDependenciesRecord, crate::error::Error>;
+
+    /// Returns one page of the crates depending on `crate_name`, most downloaded first.
+    async fn reverse_dependencies(
+        &self,
+        crate_name: &str,
+        page: u64,
+        per_page: u64,
+    ) -> Result<crate::record::crates_io::ReverseDependenciesRecord, crate::error::Error>;
 }
 
 #[derive(Debug, Default)]
 pub struct CratesIoRepositoryImpl {}
 
-#[async_trait::async_trait]
-impl CratesIoRepository for CratesIoRepositoryImpl {
-    async fn search_crate(
-        &self,
+impl CratesIoRepositoryImpl {
+    fn sort(sort: crate::record::crates_io::CrateSort) -> crates_io_api::Sort {
+        match sort {
+            crate::record::crates_io::CrateSort::Relevance => crates_io_api::Sort::Relevance,
+            crate::record::crates_io::CrateSort::Downloads => crates_io_api::Sort::Downloads,
+            crate::record::crates_io::CrateSort::RecentDownloads => {
+                crates_io_api::Sort::RecentDownloads
+            }
+            crate::record::crates_io::CrateSort::RecentlyUpdated => {
+                crates_io_api::Sort::RecentUpdates
+            }
+            crate::record::crates_io::CrateSort::NewlyAdded => crates_io_api::Sort::NewlyAdded,
+            crate::record::crates_io::CrateSort::Alphabetical => crates_io_api::Sort::Alphabetical,
+        }
+    }
+
+    /// `crates_io_api` cannot filter by keyword (tag), so such searches query crates.io directly.
+    async fn search_crate_by_keyword(
+        query: &crate::record::crates_io::CrateSearchQuery,
         keyword: &str,
-    ) -> Result<Vec<crate::record::crates_io::CrateRecord>, crate::error::Error> {
-        let client = crate::cache::get_or_init_crates_io_api_client().await?;
+    ) -> Result<crates_io_api::CratesPage, crate::error::Error> {
+        let client = crate::cache::get_or_init_reqwest_client().await?;
 
-        let query = crates_io_api::CratesQuery::builder()
-            .page_size(10)
-            .search(keyword)
-            .sort(crates_io_api::Sort::Relevance)
-            .build();
+        let sort = match query.sort {
+            crate::record::crates_io::CrateSort::Relevance => "relevance",
+            crate::record::crates_io::CrateSort::Downloads => "downloads",
+            crate::record::crates_io::CrateSort::RecentDownloads => "recent-downloads",
+            crate::record::crates_io::CrateSort::RecentlyUpdated => "recent-updates",
+            crate::record::crates_io::CrateSort::NewlyAdded => "new",
+            crate::record::crates_io::CrateSort::Alphabetical => "alpha",
+        };
 
-        let response = client
-            .crates(query)
+        let mut params = vec![
+            ("page", query.page.to_string()),
+            ("per_page", query.per_page.to_string()),
+            ("sort", sort.to_owned()),
+            ("keyword", keyword.to_owned()),
+        ];
+        if !query.search.is_empty() {
+            params.push(("q", query.search.clone()));
+        }
+        if let Some(category) = &query.category {
+            params.push(("category", category.clone()));
+        }
+
+        let url = reqwest::Url::parse_with_params("https://crates.io/api/v1/crates", &params)
+            .map_err(|e| crate::error::Error::CratesIoApi(e.to_string()))?;
+
+        let bytes = client
+            .get(url)
+            .send()
             .await
+            .and_then(|response| response.error_for_status())
             .map_err(|e| {
                 tracing::error!("{}", e);
                 crate::error::Error::CratesIoApi(e.to_string())
             })?
+            .bytes()
+            .await
+            .map_err(|e| {
+                tracing::error!("{}", e);
+                crate::error::Error::CratesIoApi(e.to_string())
+            })?;
+
+        serde_json::from_slice(&bytes).map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::CratesIoApi(e.to_string())
+        })
+    }
+
+    fn version_record(v: crates_io_api::Version) -> crate::record::crates_io::VersionRecord {
+        crate::record::crates_io::VersionRecord {
+            num: v.num,
+            yanked: v.yanked,
+            created_at: v.created_at.to_rfc3339(),
+            downloads: v.downloads,
+            license: v.license,
+            rust_version: v.rust_version,
+            features: v.features.into_iter().collect(),
+        }
+    }
+
+    /// `crates_io_api` does not expose the edition, so it is read from the version endpoint directly.
+    async fn fetch_edition(&self, crate_name: &str, version: &str) -> Option<String> {
+        let client = crate::cache::get_or_init_reqwest_client().await.ok()?;
+
+        let url = format!("https://crates.io/api/v1/crates/{crate_name}/{version}");
+
+        let response = client
+            .get(&url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .inspect_err(|e| tracing::warn!("Failed to fetch edition of {crate_name}: {e}"))
+            .ok()?;
+
+        let bytes = response.bytes().await.ok()?;
+        let json = serde_json::from_slice::<serde_json::Value>(&bytes).ok()?;
+
+        json.get("version")?
+            .get("edition")?
+            .as_str()
+            .map(str::to_owned)
+    }
+}
+
+#[async_trait::async_trait]
+impl CratesIoRepository for CratesIoRepositoryImpl {
+    async fn search_crate(
+        &self,
+        query: &crate::record::crates_io::CrateSearchQuery,
+    ) -> Result<crate::record::crates_io::CrateSearchRecord, crate::error::Error> {
+        let page = match &query.keyword {
+            Some(keyword) => Self::search_crate_by_keyword(query, keyword).await?,
+            None => {
+                let client = crate::cache::get_or_init_crates_io_api_client().await?;
+
+                let mut builder = crates_io_api::CratesQuery::builder()
+                    .page(query.page)
+                    .page_size(query.per_page)
+                    .sort(Self::sort(query.sort));
+                if !query.search.is_empty() {
+                    builder = builder.search(&query.search);
+                }
+                if let Some(category) = &query.category {
+                    builder = builder.category(category);
+                }
+
+                client.crates(builder.build()).await.map_err(|e| {
+                    tracing::error!("{}", e);
+                    crate::error::Error::CratesIoApi(e.to_string())
+                })?
+            }
+        };
+
+        let crates = page
             .crates
             .into_iter()
             .map(|c| crate::record::crates_io::CrateRecord {
@@ -40,10 +179,197 @@ impl CratesIoRepository for CratesIoRepositoryImpl {
                 downloads: c.downloads,
                 created_at: c.created_at.to_rfc3339(),
                 updated_at: c.updated_at.to_rfc3339(),
-                ..Default::default()
             })
             .collect::<Vec<crate::record::crates_io::CrateRecord>>();
 
+        Ok(crate::record::crates_io::CrateSearchRecord {
+            total: page.meta.total,
+            crates,
+        })
+    }
+
+    async fn list_versions(
+        &self,
+        crate_name: &str,
+    ) -> Result<Vec<crate::record::crates_io::VersionRecord>, crate::error::Error> {
+        let client = crate::cache::get_or_init_crates_io_api_client().await?;
+
+        let response = client
+            .get_crate(crate_name)
+            .await
+            .map_err(|e| {
+                tracing::error!("{}", e);
+                crate::error::Error::CratesIoApi(e.to_string())
+            })?
+            .versions
+            .into_iter()
+            .map(Self::version_record)
+            .collect::<Vec<crate::record::crates_io::VersionRecord>>();
+
         Ok(response)
     }
+
+    async fn get_crate(
+        &self,
+        crate_name: &str,
+    ) -> Result<crate::record::crates_io::CrateDetailRecord, crate::error::Error> {
+        let client = crate::cache::get_or_init_crates_io_api_client().await?;
+
+        let response = client.get_crate(crate_name).await.map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::CratesIoApi(e.to_string())
+        })?;
+
+        let owners = client
+            .crate_owners(crate_name)
+            .await
+            .map_err(|e| {
+                tracing::error!("{}", e);
+                crate::error::Error::CratesIoApi(e.to_string())
+            })?
+            .into_iter()
+            .map(|u| crate::record::crates_io::OwnerRecord {
+                login: u.login,
+                name: u.name,
+                kind: u.kind,
+                url: u.url,
+            })
+            .collect::<Vec<crate::record::crates_io::OwnerRecord>>();
+
+        let c = response.crate_data;
+        let latest = c
+            .max_stable_version
+            .clone()
+            .unwrap_or_else(|| c.max_version.clone());
+
+        let versions = response
+            .versions
+            .into_iter()
+            .map(Self::version_record)
+            .collect::<Vec<crate::record::crates_io::VersionRecord>>();
+        let latest_record = versions.iter().find(|v| v.num == latest);
+
+        Ok(crate::record::crates_io::CrateDetailRecord {
+            license: latest_record.and_then(|v| v.license.clone()),
+            rust_version: latest_record.and_then(|v| v.rust_version.clone()),
+            edition: self.fetch_edition(crate_name, &latest).await,
+            name: c.name,
+            description: c.description,
+            repository: c.repository,
+            homepage: c.homepage,
+            documentation: c.documentation,
+            keywords: response.keywords.into_iter().map(|k| k.keyword).collect(),
+            categories: response
+                .categories
+                .into_iter()
+                .map(|c| c.category)
+                .collect(),
+            owners,
+            downloads: c.downloads,
+            recent_downloads: c.recent_downloads,
+            latest_stable_version: c.max_stable_version,
+            latest_version: c.max_version,
+            created_at: c.created_at.to_rfc3339(),
+            updated_at: c.updated_at.to_rfc3339(),
+            versions,
+        })
+    }
+
+    async fn get_dependencies(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<crate::record::crates_io::CrateDependenciesRecord, crate::error::Error> {
+        let client = crate::cache::get_or_init_crates_io_api_client().await?;
+
+        let dependencies = client
+            .crate_dependencies(crate_name, version)
+            .await
+            .map_err(|e| {
+                tracing::error!("{}", e);
+                crate::error::Error::CratesIoApi(e.to_string())
+            })?
+            .into_iter()
+            .map(|d| crate::record::crates_io::DependencyRecord {
+                name: d.crate_id,
+                req: d.req,
+                kind: d.kind,
+                optional: d.optional,
+                default_features: d.default_features,
+                features: d.features,
+                target: d.target,
+            })
+            .collect::<Vec<crate::record::crates_io::DependencyRecord>>();
+
+        let features = self
+            .list_versions(crate_name)
+            .await?
+            .into_iter()
+            .find(|v| v.num == version)
+            .map(|v| v.features)
+            .unwrap_or_default();
+
+        Ok(crate::record::crates_io::CrateDependenciesRecord {
+            dependencies,
+            features,
+        })
+    }
+
+    async fn reverse_dependencies(
+        &self,
+        crate_name: &str,
+        page: u64,
+        per_page: u64,
+    ) -> Result<crate::record::crates_io::ReverseDependenciesRecord, crate::error::Error> {
+        // crates.io serves reverse dependencies in fixed pages of 100.
+        const UPSTREAM_PAGE_SIZE: u64 = 100;
+
+        let client = crate::cache::get_or_init_crates_io_api_client().await?;
+
+        // `page` comes straight from the client and may be arbitrarily large.
+        let start = (page.max(1) - 1).saturating_mul(per_page);
+        let end = start.saturating_add(per_page);
+
+        let mut total = 0;
+        let mut dependencies = Vec::new();
+
+        for upstream_page in (start / UPSTREAM_PAGE_SIZE)..end.div_ceil(UPSTREAM_PAGE_SIZE) {
+            let response = client
+                .crate_reverse_dependencies_page(crate_name, upstream_page + 1)
+                .await
+                .map_err(|e| {
+                    tracing::error!("{}", e);
+                    crate::error::Error::CratesIoApi(e.to_string())
+                })?;
+
+            total = response.meta.total;
+
+            let offset = upstream_page * UPSTREAM_PAGE_SIZE;
+
+            dependencies.extend(
+                response
+                    .dependencies
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| (start..end).contains(&(offset + *i as u64)))
+                    .map(|(_, d)| crate::record::crates_io::ReverseDependencyRecord {
+                        crate_name: d.crate_version.crate_name,
+                        version: d.crate_version.num,
+                        req: d.dependency.req,
+                        kind: d.dependency.kind,
+                        optional: d.dependency.optional,
+                        downloads: d.crate_version.downloads,
+                    }),
+            );
+
+            if offset.saturating_add(UPSTREAM_PAGE_SIZE) >= total {
+                break;
+            }
+        }
+
+        Ok(crate::record::crates_io::ReverseDependenciesRecord {
+            total,
+            dependencies,
+        })
+    }
 }
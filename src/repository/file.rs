@@ -0,0 +1,40 @@
+#[async_trait::async_trait]
+pub trait FileRepository: std::fmt::Debug + Send + Sync {
+    async fn read(&self, path: &std::path::Path) -> Result<Vec<u8>, crate::error::Error>;
+
+    /// Last modification time of `path`, or `None` when it does not exist.
+    async fn modified(&self, path: &std::path::Path) -> Option<std::time::SystemTime>;
+
+    async fn read_to_string(&self, path: &std::path::Path) -> Result<String, crate::error::Error> {
+        let bytes = self.read(path).await?;
+
+        String::from_utf8(bytes).map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::LocalDocs(format!("{} is not valid UTF-8", path.display()))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct FileRepositoryImpl {}
+
+#[async_trait::async_trait]
+impl FileRepository for FileRepositoryImpl {
+    async fn read(&self, path: &std::path::Path) -> Result<Vec<u8>, crate::error::Error> {
+        tokio::fs::read(path).await.map_err(|e| {
+            // Callers probe for pages and lockfiles, so missing files are expected.
+            if e.kind() == std::io::ErrorKind::NotFound {
+                tracing::debug!("{}: {}", path.display(), e);
+            } else {
+                tracing::error!("{}: {}", path.display(), e);
+            }
+            crate::error::Error::LocalDocs(format!("{}: {}", path.display(), e))
+        })
+    }
+
+    async fn modified(&self, path: &std::path::Path) -> Option<std::time::SystemTime> {
+        let metadata = tokio::fs::metadata(path).await.ok()?;
+
+        metadata.modified().ok()
+    }
+}
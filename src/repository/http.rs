@@ -1,6 +1,15 @@
 #[async_trait::async_trait]
 pub trait HttpRepository: std::fmt::Debug + Send + Sync {
-    async fn get(&self, url: &str) -> Result<String, crate::error::Error>;
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, crate::error::Error>;
+
+    async fn get(&self, url: &str) -> Result<String, crate::error::Error> {
+        let bytes = self.get_bytes(url).await?;
+
+        String::from_utf8(bytes).map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::Http(format!("Response from {} is not valid UTF-8", url))
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -8,7 +17,7 @@ pub struct HttpRepositoryImpl {}
 
 #[async_trait::async_trait]
 impl HttpRepository for HttpRepositoryImpl {
-    async fn get(&self, url: &str) -> Result<String, crate::error::Error> {
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, crate::error::Error> {
         let client = crate::cache::get_or_init_reqwest_client().await?;
 
         let response = client.get(url).send().await.map_err(|e| {
@@ -24,11 +33,305 @@ impl HttpRepository for HttpRepositoryImpl {
             )));
         }
 
-        let html = response.text().await.map_err(|e| {
+        let bytes = response.bytes().await.map_err(|e| {
             tracing::error!("{}", e);
             crate::error::Error::Http(e.to_string())
         })?;
 
-        Ok(html)
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Wraps another [`HttpRepository`] with a content-addressed on-disk cache.
+///
+/// Responses are keyed by the SHA-256 of the URL. URLs pointing at a concrete
+/// version (e.g. `https://docs.rs/serde/1.0.228/...`) are immutable and kept until
+/// evicted, everything else (e.g. `latest`) is refetched once older than `ttl`.
+/// When the cache grows beyond `max_bytes`, the least recently used entries are evicted.
+#[derive(Debug)]
+pub struct CachedHttpRepositoryImpl {
+    inner: std::sync::Arc<dyn HttpRepository + Send + Sync>,
+    dir: std::path::PathBuf,
+    ttl: std::time::Duration,
+    max_bytes: u64,
+    /// Total size of the cached entries, or `None` until the cache directory has been scanned.
+    /// Grows with every write, so it overestimates overwritten entries until the next scan.
+    total_bytes: std::sync::Mutex<Option<u64>>,
+    eviction_lock: tokio::sync::Mutex<()>,
+}
+
+impl CachedHttpRepositoryImpl {
+    pub fn new(
+        inner: std::sync::Arc<dyn HttpRepository + Send + Sync>,
+        config: &crate::config::HttpCacheConfig,
+    ) -> Self {
+        Self {
+            inner,
+            dir: config.dir.clone(),
+            ttl: config.ttl,
+            max_bytes: config.max_bytes,
+            total_bytes: std::sync::Mutex::new(None),
+            eviction_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn entry_path(&self, url: &str) -> std::path::PathBuf {
+        use sha2::Digest;
+
+        let key = sha2::Sha256::digest(url.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+
+        self.dir.join(&key[..2]).join(key)
+    }
+
+    /// Returns `true` if the content behind the URL can never change,
+    /// i.e. one of its path segments is an exact semver version and none is `latest`.
+    pub(crate) fn is_immutable(url: &str) -> bool {
+        let Ok(url) = reqwest::Url::parse(url) else {
+            return false;
+        };
+
+        let Some(segments) = url.path_segments() else {
+            return false;
+        };
+
+        let segments = segments.collect::<Vec<&str>>();
+
+        !segments.contains(&"latest")
+            && segments
+                .iter()
+                .any(|segment| semver::Version::parse(segment).is_ok())
+    }
+
+    async fn read_entry(&self, url: &str, path: &std::path::Path) -> Option<Vec<u8>> {
+        let metadata = tokio::fs::metadata(path).await.ok()?;
+
+        let immutable = Self::is_immutable(url);
+
+        if !immutable {
+            let age = metadata.modified().ok()?.elapsed().unwrap_or_default();
+            if age > self.ttl {
+                return None;
+            }
+        }
+
+        let body = tokio::fs::read(path).await.ok()?;
+
+        // Immutable entries never expire, so their mtime doubles as the last access time for eviction.
+        if immutable {
+            let path = path.to_owned();
+            let _ = tokio::task::spawn_blocking(move || {
+                std::fs::File::options()
+                    .write(true)
+                    .open(path)
+                    .and_then(|file| file.set_modified(std::time::SystemTime::now()))
+            })
+            .await;
+        }
+
+        Some(body)
+    }
+
+    async fn write_entry(&self, path: &std::path::Path, body: &[u8]) -> std::io::Result<()> {
+        let parent = path.parent().unwrap_or(&self.dir);
+        tokio::fs::create_dir_all(parent).await?;
+
+        // Write to a uniquely named temporary file first so that neither concurrent readers nor
+        // concurrent writers of the same URL ever observe a partial entry.
+        let parent = parent.to_owned();
+        let path = path.to_owned();
+        let body = body.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            use std::io::Write;
+
+            let mut file = tempfile::NamedTempFile::new_in(parent)?;
+            file.write_all(&body)?;
+            file.persist(path).map_err(|e| e.error)?;
+
+            Ok(())
+        })
+        .await
+        .map_err(std::io::Error::other)?
+    }
+
+    /// Accounts for a newly written entry and, once the cache exceeds `max_bytes`, rescans the
+    /// cache directory and removes the least recently used entries.
+    async fn evict(&self, written: u64) -> std::io::Result<()> {
+        {
+            let mut total_bytes = self.total_bytes.lock().unwrap();
+            if let Some(total_bytes) = total_bytes.as_mut() {
+                *total_bytes += written;
+                if *total_bytes <= self.max_bytes {
+                    return Ok(());
+                }
+            }
+        }
+
+        let Ok(_guard) = self.eviction_lock.try_lock() else {
+            return Ok(());
+        };
+
+        let mut entries = Vec::new();
+        let mut total = 0u64;
+
+        // Entries may be replaced or removed concurrently, so vanished files are skipped.
+        let mut shards = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(shard) = shards.next_entry().await? {
+            let Ok(mut files) = tokio::fs::read_dir(shard.path()).await else {
+                continue;
+            };
+            while let Ok(Some(file)) = files.next_entry().await {
+                let Ok(metadata) = file.metadata().await else {
+                    continue;
+                };
+                if !metadata.is_file() {
+                    continue;
+                }
+                total += metadata.len();
+                entries.push((
+                    metadata.modified().unwrap_or(std::time::UNIX_EPOCH),
+                    metadata.len(),
+                    file.path(),
+                ));
+            }
+        }
+
+        if total > self.max_bytes {
+            entries.sort_by_key(|(modified, _, _)| *modified);
+
+            for (_, len, path) in entries {
+                if total <= self.max_bytes {
+                    break;
+                }
+                match tokio::fs::remove_file(&path).await {
+                    Ok(()) => total = total.saturating_sub(len),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                        total = total.saturating_sub(len)
+                    }
+                    Err(e) => tracing::warn!("Failed to evict {}: {}", path.display(), e),
+                }
+            }
+        }
+
+        *self.total_bytes.lock().unwrap() = Some(total);
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpRepository for CachedHttpRepositoryImpl {
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, crate::error::Error> {
+        let path = self.entry_path(url);
+
+        if let Some(body) = self.read_entry(url, &path).await {
+            tracing::debug!("HTTP cache hit: {}", url);
+            return Ok(body);
+        }
+
+        let body = self.inner.get_bytes(url).await?;
+
+        if let Err(e) = self.write_entry(&path, &body).await {
+            tracing::warn!("Failed to write HTTP cache entry for {}: {}", url, e);
+        } else if let Err(e) = self.evict(body.len() as u64).await {
+            tracing::warn!("Failed to evict HTTP cache entries: {}", e);
+        }
+
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HttpRepository;
+
+    #[derive(Debug, Default)]
+    struct CountingHttpRepository {
+        count: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl super::HttpRepository for CountingHttpRepository {
+        async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, crate::error::Error> {
+            self.count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(format!("body of {url}").into_bytes())
+        }
+    }
+
+    #[test]
+    fn test_is_immutable() {
+        assert!(super::CachedHttpRepositoryImpl::is_immutable(
+            "https://docs.rs/serde/1.0.228/serde/index.html"
+        ));
+        assert!(!super::CachedHttpRepositoryImpl::is_immutable(
+            "https://docs.rs/serde/latest/serde/index.html"
+        ));
+        assert!(!super::CachedHttpRepositoryImpl::is_immutable(
+            "https://docs.rs/serde/^1.0/serde/index.html"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_evict() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let inner = std::sync::Arc::new(CountingHttpRepository::default());
+        let body_len = "body of https://docs.rs/crate/0.1.0/page".len() as u64;
+        let repository = super::CachedHttpRepositoryImpl::new(
+            inner.clone(),
+            &crate::config::HttpCacheConfig {
+                enabled: true,
+                dir: dir.path().to_owned(),
+                ttl: std::time::Duration::ZERO,
+                max_bytes: 2 * body_len,
+            },
+        );
+
+        for page in ["page", "next", "last"] {
+            repository
+                .get(&format!("https://docs.rs/crate/0.1.0/{page}"))
+                .await?;
+        }
+
+        // One entry was evicted once the third one exceeded the limit.
+        assert_eq!(*repository.total_bytes.lock().unwrap(), Some(2 * body_len));
+
+        let mut entries = 0;
+        for shard in std::fs::read_dir(dir.path()).unwrap() {
+            entries += std::fs::read_dir(shard.unwrap().path()).unwrap().count();
+        }
+        assert_eq!(entries, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cached_get() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let inner = std::sync::Arc::new(CountingHttpRepository::default());
+        let repository = super::CachedHttpRepositoryImpl::new(
+            inner.clone(),
+            &crate::config::HttpCacheConfig {
+                enabled: true,
+                dir: dir.path().to_owned(),
+                ttl: std::time::Duration::ZERO,
+                max_bytes: u64::MAX,
+            },
+        );
+
+        let pinned = "https://docs.rs/serde/1.0.228/serde/index.html";
+        let latest = "https://docs.rs/serde/latest/serde/index.html";
+
+        assert_eq!(repository.get(pinned).await?, format!("body of {pinned}"));
+        assert_eq!(repository.get(pinned).await?, format!("body of {pinned}"));
+        assert_eq!(inner.count.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        repository.get(latest).await?;
+        repository.get(latest).await?;
+        assert_eq!(inner.count.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        Ok(())
     }
 }
@@ -1,2 +1,3 @@
 pub mod crates_io;
+pub mod file;
 pub mod http;
@@ -2,6 +2,22 @@
 pub struct SearchCrateParams {
     /// Keyword for searching crates on crates.io. Searches by crate name.
     pub keyword: String,
+
+    /// Page number, starting at 1. Defaults to 1.
+    pub page: Option<u64>,
+
+    /// Number of crates per page, up to 100. Defaults to 10.
+    pub per_page: Option<u64>,
+
+    /// `relevance` (default), `downloads`, `recent_downloads`, `recently_updated`,
+    /// `newly_added` or `alphabetical`.
+    pub sort: Option<crate::record::crates_io::CrateSort>,
+
+    /// Only return crates in this crates.io category slug, e.g. `command-line-utilities`.
+    pub category: Option<String>,
+
+    /// Only return crates tagged with this crates.io keyword, e.g. `async`.
+    pub tag: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, rmcp::schemars::JsonSchema)]
@@ -15,13 +31,94 @@ pub struct SearchCrateResult {
     pub updated_at: String,
 }
 
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct GetCrateParams {
+    /// Exact name of the crate on crates.io
+    pub crate_name: String,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RetrieveCrateDependenciesParams {
+    /// Exact name of the crate on crates.io
+    pub crate_name: String,
+
+    /// Crate version. For v1.0.0, use `1.0.0`. For the latest version, use `latest`.
+    /// Semver requirements such as `^1.2` or `1` are resolved to the newest matching version.
+    /// Defaults to the version locked in the configured workspace, or `latest` for other crates.
+    pub version: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ReverseDependenciesParams {
+    /// Exact name of the crate on crates.io
+    pub crate_name: String,
+
+    /// Page number, starting at 1. Defaults to 1.
+    pub page: Option<u64>,
+
+    /// Number of dependent crates per page, up to 100. Defaults to 20.
+    pub per_page: Option<u64>,
+
+    /// `downloads` (default) or `name`.
+    pub sort: Option<crate::use_case::crates_io::ReverseDependencySort>,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct ListWorkspaceDependenciesParams {
+    /// Only list the crates the workspace members depend on directly. Defaults to `false`.
+    pub direct_only: Option<bool>,
+}
+
+// Shared by the parameters of the documentation tools through `#[serde(flatten)]`.
+// Not a doc comment: schemars would make it the description of every flattening struct.
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct VersionParams {
+    /// Crate version. For v1.0.0, use `1.0.0`. For the latest version, use `latest`.
+    /// Semver requirements such as `^1.2` or `1` are resolved to the newest matching version.
+    /// For the standard library, use `stable`, `beta`, `nightly` or a Rust release such as `1.80.0`.
+    /// Defaults to the version locked in the configured workspace, or `latest` for other crates.
+    pub version: Option<String>,
+}
+
+// Shared like `VersionParams`.
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SourceParams {
+    /// `docs_rs`, `rust_lang` (doc.rust-lang.org), `toolchain` (std docs of the installed toolchain, offline)
+    /// or `local` (`cargo doc` output of private crates).
+    /// Defaults to `rust_lang` for `std`, `core`, `alloc` and `proc_macro`,
+    /// to `local` for crates configured in `MCP_RUST_DOCS_LOCAL_CRATES`, and to `docs_rs` otherwise.
+    pub source: Option<crate::entity::docs::DocsSource>,
+}
+
 #[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
 pub struct RetrieveDocumentationIndexPageParams {
     /// Name of the crate
     pub crate_name: String,
 
-    /// Crate version. For v1.0.0, use `1.0.0`. For the latest version, use `latest`.
-    pub version: String,
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+
+    /// Maximum size of the returned markdown in bytes. Long pages are split before headings
+    /// and the response ends with a `next_cursor` to fetch the rest. Returns the whole page when omitted.
+    pub max_length: Option<usize>,
+
+    /// `next_cursor` of the previous response, to continue a paginated page.
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RetrieveDocumentationAllItemsParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
 }
 
 #[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
@@ -29,11 +126,94 @@ pub struct RetrieveDocumentationPageParams {
     /// Name of the crate
     pub crate_name: String,
 
-    /// Crate version. For v1.0.0, use `1.0.0`. For the latest version, use `latest`.
-    pub version: String,
+    #[serde(flatten)]
+    pub version: VersionParams,
 
     /// This is not a search query; you need to know the exact link path in advance.
     pub path: String,
+
+    /// Maximum size of the returned markdown in bytes. Long pages are split before headings
+    /// and the response ends with a `next_cursor` to fetch the rest. Returns the whole page when omitted.
+    pub max_length: Option<usize>,
+
+    /// `next_cursor` of the previous response, to continue a paginated page.
+    pub cursor: Option<String>,
+
+    /// `markdown` (default) returns the whole page as markdown.
+    /// `structured` returns the item page as JSON split into declaration, docs, examples,
+    /// methods, trait implementations, auto trait implementations and blanket implementations.
+    pub format: Option<PageFormat>,
+
+    /// With `structured` format, only return these parts, e.g. `["declaration", "methods"]`.
+    /// Returns every part when omitted.
+    pub sections: Option<Vec<crate::entity::docs::ItemPageSection>>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RetrieveDocumentationMemberParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    /// Path of the item page the member belongs to, e.g. `/vec/struct.Vec.html`.
+    pub path: String,
+
+    /// Name of the method, associated const or type, variant or field, e.g. `retain`.
+    /// An anchor such as `method.retain` or `variant.Some` picks a specific kind of member.
+    pub member: String,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RetrieveDocumentationByPathParams {
+    /// Fully-qualified Rust path, e.g. `tokio::sync::mpsc::Sender` or `std::vec::Vec::retain`.
+    pub rust_path: String,
+
+    /// Name of the crate. Defaults to the first segment of `rust_path`.
+    pub crate_name: Option<String>,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    /// Kind of the item when the name is ambiguous: `module`, `struct`, `enum`, `trait`, `fn`,
+    /// `macro`, `type`, `constant`, `static`, `union`, `derive`, `attr` or `primitive`.
+    pub kind: Option<String>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+
+    /// Maximum size of the returned markdown in bytes. Long pages are split before headings
+    /// and the response ends with a `next_cursor` to fetch the rest. Returns the whole page when omitted.
+    pub max_length: Option<usize>,
+
+    /// `next_cursor` of the previous response, to continue a paginated page.
+    pub cursor: Option<String>,
+}
+
+/// Chunk size used when a client continues with a `cursor` but without a `max_length`.
+const DEFAULT_PAGE_LENGTH: usize = 20_000;
+
+/// Upper bound of the `limit` of the search tools.
+const MAX_SEARCH_LIMIT: usize = 100;
+
+/// `limit` of a search tool, 10 when omitted. Tantivy rejects a limit of 0.
+fn search_limit(limit: Option<usize>) -> usize {
+    limit.unwrap_or(10).clamp(1, MAX_SEARCH_LIMIT)
+}
+
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize, rmcp::schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PageFormat {
+    #[default]
+    Markdown,
+    Structured,
 }
 
 #[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
@@ -41,11 +221,112 @@ pub struct SearchDocumentationItemsParams {
     /// Name of the crate
     pub crate_name: String,
 
-    /// Crate version. For v1.0.0, use `1.0.0`. For the latest version, use `latest`.
-    pub version: String,
+    #[serde(flatten)]
+    pub version: VersionParams,
 
-    /// Keyword(s) for fuzzy searching items.
+    /// Keyword(s) for fuzzy searching items. Matched against item paths, the summaries of
+    /// their documentation and function signatures (e.g. `returns Result<Duration>`).
     pub keyword: String,
+
+    /// Maximum number of results, from 1 to 100. Defaults to 10.
+    pub limit: Option<usize>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SearchBySignatureParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    /// Type signature such as `NaiveDate -> DateTime<Utc>`, `&str, usize -> Option<char>`,
+    /// `-> Duration` (any inputs) or `Instant` (anywhere in the signature).
+    /// `_` and single capital letters such as `T` match any type.
+    pub query: String,
+
+    /// Maximum number of results. Defaults to 10.
+    pub limit: Option<usize>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SearchDocumentationTextParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    /// Words or concepts to find in the documentation prose, e.g. `graceful shutdown`.
+    pub query: String,
+
+    /// Maximum number of results, from 1 to 100. Defaults to 10.
+    pub limit: Option<usize>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct DiffVersionsParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    /// Version to upgrade from. For v1.0.0, use `1.0.0`. Semver requirements such as `0.11`
+    /// are resolved to the newest matching version.
+    /// Defaults to the version locked in the configured workspace, or `latest` for other crates.
+    pub old_version: Option<String>,
+
+    /// Version to upgrade to, in the same format. Defaults to `latest`.
+    pub new_version: Option<String>,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct RetrieveChangelogParams {
+    /// Exact name of the crate on crates.io
+    pub crate_name: String,
+
+    /// Version to upgrade from; its own entry is not included. For v1.0.0, use `1.0.0`.
+    /// Semver requirements such as `0.11` are resolved to the newest matching version.
+    /// Defaults to the version locked in the configured workspace, or `latest` for other crates.
+    pub from_version: Option<String>,
+
+    /// Version to upgrade to, in the same format; its entry is included. Defaults to `latest`.
+    /// When it is not newer than `from_version`, only its own entry is returned.
+    pub to_version: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct CrateVersionParams {
+    /// Name of the crate
+    pub crate_name: String,
+
+    #[serde(flatten)]
+    pub version: VersionParams,
+
+    #[serde(flatten)]
+    pub source: SourceParams,
+}
+
+#[derive(Debug, serde::Deserialize, rmcp::schemars::JsonSchema)]
+pub struct SearchAcrossCratesParams {
+    /// Crates to search, e.g. `[{"crate_name": "tokio"}, {"crate_name": "bytes", "version": "1"}]`.
+    pub crates: Vec<CrateVersionParams>,
+
+    /// Keyword(s) for fuzzy searching items, as in `search_documentation_items`.
+    pub keyword: String,
+
+    /// Maximum number of results over all crates, from 1 to 100. Defaults to 10.
+    pub limit: Option<usize>,
 }
 
 #[rmcp::tool_router]
@@ -53,31 +334,215 @@ impl crate::handler::Handler {
     pub fn new(
         crates_io_use_case: crate::use_case::crates_io::CratesIoUseCase,
         docs_use_case: crate::use_case::docs::DocsUseCase,
+        workspace_use_case: crate::use_case::workspace::WorkspaceUseCase,
     ) -> Self {
         Self {
             crates_io_use_case,
             docs_use_case,
+            workspace_use_case,
             tool_router: Self::tool_router(),
             resource_map: crate::resource::ResourceMap::new(),
         }
     }
 
+    /// Without a version, documentation follows the version the configured workspace is
+    /// locked to, so answers match the code being written rather than `latest`.
+    async fn resolve_version(
+        &self,
+        crate_name: &str,
+        version: Option<&str>,
+        source: Option<crate::entity::docs::DocsSource>,
+    ) -> Result<crate::entity::docs::ResolvedVersion, rmcp::ErrorData> {
+        let locked_version = match version {
+            Some(_) => None,
+            None => self
+                .workspace_use_case
+                .locked_version(crate_name)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!("Could not read the locked version of {crate_name}: {e}");
+                    None
+                }),
+        };
+        let version = version.or(locked_version.as_deref()).unwrap_or("latest");
+
+        self.docs_use_case
+            .resolve_version(crate_name, version, source)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// Every docs tool reports the concrete version it answered with as its first content.
+    fn resolved_version_content(
+        resolved: &crate::entity::docs::ResolvedVersion,
+    ) -> rmcp::model::Content {
+        rmcp::model::Content::text(serde_json::to_string(resolved).unwrap())
+    }
+
+    /// Returns a markdown page, or the chunk at `cursor` followed by its position
+    /// when the page is paginated.
+    fn markdown_contents(
+        &self,
+        markdown: String,
+        max_length: Option<usize>,
+        cursor: Option<String>,
+    ) -> Result<Vec<rmcp::model::Content>, rmcp::ErrorData> {
+        if max_length.is_none() && cursor.is_none() {
+            return Ok(vec![rmcp::model::Content::text(markdown)]);
+        }
+
+        let (chunk, position) = self
+            .docs_use_case
+            .paginate(
+                &markdown,
+                cursor.as_deref(),
+                max_length.unwrap_or(DEFAULT_PAGE_LENGTH),
+            )
+            .map_err(|e| e.into())?;
+
+        Ok(vec![
+            rmcp::model::Content::text(chunk),
+            rmcp::model::Content::text(serde_json::to_string(&position).unwrap()),
+        ])
+    }
+
     /// Search for crates on crates.io and retrieve crate summaries.
+    /// Results are paginated; `total` and `next_page` tell whether more pages exist.
     #[rmcp::tool]
     async fn search_crate(
         &self,
-        rmcp::handler::server::wrapper::Parameters(SearchCrateParams { keyword }): rmcp::handler::server::wrapper::Parameters<SearchCrateParams>,
+        rmcp::handler::server::wrapper::Parameters(SearchCrateParams {
+            keyword,
+            page,
+            per_page,
+            sort,
+            category,
+            tag,
+        }): rmcp::handler::server::wrapper::Parameters<SearchCrateParams>,
     ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
-        let entities = self
+        let query = crate::record::crates_io::CrateSearchQuery {
+            search: keyword,
+            page: page.unwrap_or(1),
+            per_page: per_page.unwrap_or(10),
+            sort: sort.unwrap_or_default(),
+            category,
+            keyword: tag,
+        };
+
+        let entity = self
             .crates_io_use_case
-            .search_crate(&keyword)
+            .search_crate(query)
+            .await
+            .map_err(|e| e.into())?;
+
+        let result = rmcp::model::Content::text(serde_json::to_string(&entity).unwrap());
+
+        Ok(rmcp::model::CallToolResult::success(vec![result]))
+    }
+
+    /// Retrieves the full crates.io metadata of a crate: repository, homepage and documentation URLs,
+    /// license, keywords, categories, MSRV (`rust_version`), edition, owners, downloads,
+    /// and every published version with its yanked flag.
+    #[rmcp::tool]
+    async fn get_crate(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(GetCrateParams { crate_name }): rmcp::handler::server::wrapper::Parameters<GetCrateParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let entity = self
+            .crates_io_use_case
+            .get_crate(&crate_name)
+            .await
+            .map_err(|e| e.into())?;
+
+        let result = rmcp::model::Content::text(serde_json::to_string(&entity).unwrap());
+
+        Ok(rmcp::model::CallToolResult::success(vec![result]))
+    }
+
+    /// Retrieves the dependencies (normal, dev and build; optional and target-specific) and the
+    /// cargo feature map of a crate version, including which features turn on which optional dependencies.
+    #[rmcp::tool]
+    async fn retrieve_crate_dependencies(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(RetrieveCrateDependenciesParams {
+            crate_name,
+            version,
+        }): rmcp::handler::server::wrapper::Parameters<RetrieveCrateDependenciesParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let resolved = self
+            .resolve_version(
+                &crate_name,
+                version.as_deref(),
+                Some(crate::entity::docs::DocsSource::DocsRs),
+            )
+            .await?;
+
+        let entity = self
+            .crates_io_use_case
+            .get_dependencies(&crate_name, &resolved.resolved_version)
+            .await
+            .map_err(|e| e.into())?;
+
+        let result = rmcp::model::Content::text(serde_json::to_string(&entity).unwrap());
+
+        Ok(rmcp::model::CallToolResult::success(vec![
+            Self::resolved_version_content(&resolved),
+            result,
+        ]))
+    }
+
+    /// Lists the crates on crates.io that depend on the given crate, with the version requirement
+    /// they declare and whether the dependency is optional. Results are paginated.
+    #[rmcp::tool]
+    async fn reverse_dependencies(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(ReverseDependenciesParams {
+            crate_name,
+            page,
+            per_page,
+            sort,
+        }): rmcp::handler::server::wrapper::Parameters<ReverseDependenciesParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let entity = self
+            .crates_io_use_case
+            .reverse_dependencies(
+                &crate_name,
+                page.unwrap_or(1),
+                per_page.unwrap_or(20),
+                sort.unwrap_or_default(),
+            )
+            .await
+            .map_err(|e| e.into())?;
+
+        let result = rmcp::model::Content::text(serde_json::to_string(&entity).unwrap());
+
+        Ok(rmcp::model::CallToolResult::success(vec![result]))
+    }
+
+    /// Lists the direct and transitive dependencies of the workspace the server was started with,
+    /// with the versions locked in its `Cargo.lock`. Documentation tools called without a version use these versions.
+    #[rmcp::tool]
+    async fn list_workspace_dependencies(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(ListWorkspaceDependenciesParams {
+            direct_only,
+        }): rmcp::handler::server::wrapper::Parameters<ListWorkspaceDependenciesParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let direct_only = direct_only.unwrap_or(false);
+
+        let response = self
+            .workspace_use_case
+            .dependencies()
             .await
             .map_err(|e| e.into())?
             .into_iter()
-            .map(|c| rmcp::model::Content::text(serde_json::to_string(&c).unwrap()))
+            .filter(|dependency| dependency.direct || !direct_only)
+            .map(|dependency| {
+                rmcp::model::Content::text(serde_json::to_string(&dependency).unwrap())
+            })
             .collect::<Vec<rmcp::model::Content>>();
 
-        Ok(rmcp::model::CallToolResult::success(entities))
+        Ok(rmcp::model::CallToolResult::success(response))
     }
 
     /// Retrieves the top page of a specific version of a crate from docs.rs.
@@ -86,65 +551,290 @@ impl crate::handler::Handler {
         &self,
         rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationIndexPageParams {
             crate_name,
-            version,
+            version: VersionParams { version },
+            source: SourceParams { source },
+            max_length,
+            cursor,
         }): rmcp::handler::server::wrapper::Parameters<
             RetrieveDocumentationIndexPageParams,
         >,
     ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
         let response = self
             .docs_use_case
-            .fetch_document_index_page(&crate_name, &version)
+            .fetch_document_index_page(&resolved)
             .await
             .map_err(|e| e.into())?;
 
-        let result = rmcp::model::Content::text(response);
+        let mut contents = vec![Self::resolved_version_content(&resolved)];
+        contents.extend(self.markdown_contents(response, max_length, cursor)?);
 
-        Ok(rmcp::model::CallToolResult::success(vec![result]))
+        Ok(rmcp::model::CallToolResult::success(contents))
     }
 
     /// Retrieves all items (structs, enums, functions, etc.) defined in the specified crate version from docs.rs.
     /// Use this as a fallback when a keyword search does not find the desired item.
     /// Returns a list of all discoverable items for the crate and version.
+    /// When docs.rs has rustdoc JSON for the build, items also include their kind, full path,
+    /// signature, doc summary, deprecation and stability.
     #[rmcp::tool]
     async fn retrieve_documentation_all_items(
         &self,
-        rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationIndexPageParams {
+        rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationAllItemsParams {
             crate_name,
-            version,
-        }): rmcp::handler::server::wrapper::Parameters<
-            RetrieveDocumentationIndexPageParams,
-        >,
+            version: VersionParams { version },
+            source: SourceParams { source },
+        }): rmcp::handler::server::wrapper::Parameters<RetrieveDocumentationAllItemsParams>,
     ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
-        let response = self
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let items = self
             .docs_use_case
-            .fetch_all_items(&crate_name, &version)
+            .fetch_all_items(&resolved)
             .await
             .map_err(|e| e.into())?
             .into_iter()
-            .map(|item| rmcp::model::Content::text(serde_json::to_string(&item).unwrap()))
+            .map(|item| rmcp::model::Content::text(serde_json::to_string(&item).unwrap()));
+
+        let response = std::iter::once(Self::resolved_version_content(&resolved))
+            .chain(items)
             .collect::<Vec<rmcp::model::Content>>();
 
         Ok(rmcp::model::CallToolResult::success(response))
     }
 
     /// Performs a fuzzy search for items (structs, enums, functions, etc.) in the specified crate version on docs.rs using the provided keyword.
-    /// Returns items that match the keyword.
+    /// Tolerates typos and case or word-splitting differences (`hashmap`, `HashMapp` and `hash_map` all find `HashMap`).
+    /// Returns items, methods, fields and variants whose path, summary or signature match the keyword, with their score.
     #[rmcp::tool]
     async fn search_documentation_items(
         &self,
         rmcp::handler::server::wrapper::Parameters(SearchDocumentationItemsParams {
             crate_name,
-            version,
+            version: VersionParams { version },
             keyword,
+            limit,
+            source: SourceParams { source },
         }): rmcp::handler::server::wrapper::Parameters<SearchDocumentationItemsParams>,
     ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
-        let response = self
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let hits = self
+            .docs_use_case
+            .search_items(&resolved, &keyword, search_limit(limit))
+            .await
+            .map_err(|e| e.into())?
+            .into_iter()
+            .map(|hit| rmcp::model::Content::text(serde_json::to_string(&hit).unwrap()));
+
+        let response = std::iter::once(Self::resolved_version_content(&resolved))
+            .chain(hits)
+            .collect::<Vec<rmcp::model::Content>>();
+
+        Ok(rmcp::model::CallToolResult::success(response))
+    }
+
+    /// Searches functions and methods of a crate by type signature, like Hoogle.
+    /// Results are ranked by how closely their inputs and output match the query and include their score.
+    #[rmcp::tool]
+    async fn search_by_signature(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(SearchBySignatureParams {
+            crate_name,
+            version: VersionParams { version },
+            query,
+            limit,
+            source: SourceParams { source },
+        }): rmcp::handler::server::wrapper::Parameters<SearchBySignatureParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let hits = self
+            .docs_use_case
+            .search_by_signature(&resolved, &query, limit.unwrap_or(10))
+            .await
+            .map_err(|e| e.into())?
+            .into_iter()
+            .map(|hit| rmcp::model::Content::text(serde_json::to_string(&hit).unwrap()));
+
+        let response = std::iter::once(Self::resolved_version_content(&resolved))
+            .chain(hits)
+            .collect::<Vec<rmcp::model::Content>>();
+
+        Ok(rmcp::model::CallToolResult::success(response))
+    }
+
+    /// Searches items in several crates at once, e.g. to find where `Bytes` is defined among tokio, hyper, bytes and axum.
+    /// Results of all crates are ranked together, mostly by how closely the item name matches the keyword,
+    /// and each result names the crate and version it belongs to.
+    /// The first content lists the resolved crate versions; crates that cannot be resolved are reported and skipped.
+    #[rmcp::tool]
+    async fn search_across_crates(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(SearchAcrossCratesParams {
+            crates,
+            keyword,
+            limit,
+        }): rmcp::handler::server::wrapper::Parameters<SearchAcrossCratesParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let mut targets = Vec::new();
+        let mut skipped = Vec::new();
+
+        for CrateVersionParams {
+            crate_name,
+            version: VersionParams { version },
+            source: SourceParams { source },
+        } in crates
+        {
+            match self
+                .resolve_version(&crate_name, version.as_deref(), source)
+                .await
+            {
+                Ok(resolved) => targets.push(resolved),
+                Err(e) => skipped.push(rmcp::model::Content::text(format!(
+                    "Skipped {crate_name}: {}",
+                    e.message
+                ))),
+            }
+        }
+
+        if targets.is_empty() {
+            return Ok(rmcp::model::CallToolResult::error(skipped));
+        }
+
+        let hits = self
             .docs_use_case
-            .search_items(&crate_name, &version, &keyword)
+            .search_crates(&targets, &keyword, search_limit(limit))
             .await
             .map_err(|e| e.into())?
             .into_iter()
-            .map(|item| rmcp::model::Content::text(serde_json::to_string(&item).unwrap()))
+            .map(|hit| rmcp::model::Content::text(serde_json::to_string(&hit).unwrap()));
+
+        let response = std::iter::once(rmcp::model::Content::text(
+            serde_json::to_string(&targets).unwrap(),
+        ))
+        .chain(skipped)
+        .chain(hits)
+        .collect::<Vec<rmcp::model::Content>>();
+
+        Ok(rmcp::model::CallToolResult::success(response))
+    }
+
+    /// Compares the public API of two versions of a crate, e.g. before upgrading `reqwest` from `0.11` to `0.12`.
+    /// Reports added, removed and renamed-looking items, items whose signature changed and newly deprecated items.
+    /// Signatures and deprecations are only compared when docs.rs provides rustdoc JSON for both versions.
+    /// The first two contents are the resolved old and new versions.
+    #[rmcp::tool]
+    async fn diff_versions(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(DiffVersionsParams {
+            crate_name,
+            old_version,
+            new_version,
+            source: SourceParams { source },
+        }): rmcp::handler::server::wrapper::Parameters<DiffVersionsParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let old = self
+            .resolve_version(&crate_name, old_version.as_deref(), source)
+            .await?;
+        let new = self
+            .resolve_version(
+                &crate_name,
+                Some(new_version.as_deref().unwrap_or("latest")),
+                source,
+            )
+            .await?;
+
+        let diff = self
+            .docs_use_case
+            .diff_versions(&old, &new)
+            .await
+            .map_err(|e| e.into())?;
+
+        Ok(rmcp::model::CallToolResult::success(vec![
+            Self::resolved_version_content(&old),
+            Self::resolved_version_content(&new),
+            rmcp::model::Content::text(serde_json::to_string(&diff).unwrap()),
+        ]))
+    }
+
+    /// Retrieves the changelog entries of a crate between two versions, to explain what an upgrade brings.
+    /// The changelog is read from the packaged crate, or from its GitHub or GitLab repository when it is not packaged.
+    /// Returns the source of the changelog and one section per version, newest first.
+    /// The first two contents are the resolved from and to versions.
+    #[rmcp::tool]
+    async fn retrieve_changelog(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(RetrieveChangelogParams {
+            crate_name,
+            from_version,
+            to_version,
+        }): rmcp::handler::server::wrapper::Parameters<RetrieveChangelogParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let source = Some(crate::entity::docs::DocsSource::DocsRs);
+
+        let from = self
+            .resolve_version(&crate_name, from_version.as_deref(), source)
+            .await?;
+        let to = self
+            .resolve_version(
+                &crate_name,
+                Some(to_version.as_deref().unwrap_or("latest")),
+                source,
+            )
+            .await?;
+
+        let changelog = self
+            .docs_use_case
+            .fetch_changelog(&crate_name, &from.resolved_version, &to.resolved_version)
+            .await
+            .map_err(|e| e.into())?;
+
+        Ok(rmcp::model::CallToolResult::success(vec![
+            Self::resolved_version_content(&from),
+            Self::resolved_version_content(&to),
+            rmcp::model::Content::text(serde_json::to_string(&changelog).unwrap()),
+        ]))
+    }
+
+    /// Full-text search over the documentation prose, headings and code examples of every page of a crate version.
+    /// Use it for concepts rather than item names (e.g. `backpressure`, `graceful shutdown`).
+    /// Returns ranked pages with a highlighted snippet; pass `href` as `path` to `retrieve_documentation_page`.
+    /// The first search of a crate version crawls up to 1,000 of its pages and may take a while; pages beyond that are not searched.
+    #[rmcp::tool]
+    async fn search_documentation_text(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(SearchDocumentationTextParams {
+            crate_name,
+            version: VersionParams { version },
+            query,
+            limit,
+            source: SourceParams { source },
+        }): rmcp::handler::server::wrapper::Parameters<SearchDocumentationTextParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let hits = self
+            .docs_use_case
+            .search_text(&resolved, &query, search_limit(limit))
+            .await
+            .map_err(|e| e.into())?
+            .into_iter()
+            .map(|hit| rmcp::model::Content::text(serde_json::to_string(&hit).unwrap()));
+
+        let response = std::iter::once(Self::resolved_version_content(&resolved))
+            .chain(hits)
             .collect::<Vec<rmcp::model::Content>>();
 
         Ok(rmcp::model::CallToolResult::success(response))
@@ -154,26 +844,156 @@ impl crate::handler::Handler {
     /// The URL must follow the format `https://docs.rs/{crate_name}/{version}/{crate_name}{path}`,
     /// such as `https://docs.rs/serde/latest/serde/de/value/struct.BoolDeserializer.html`.
     /// In this example, `path` is `/de/value/struct.BoolDeserializer.html`.
+    /// Standard library pages follow `https://doc.rust-lang.org/{version}/{crate_name}{path}`.
     /// If you want to explore unknown modules or structs, you can first retrieve the top page.
     /// The 'Modules' section on the top page lists top-level modules,
     /// which you can follow to find the desired module.
+    /// Set `format` to `structured` to get an item page as JSON and pick only the parts you need.
     #[rmcp::tool]
     async fn retrieve_documentation_page(
         &self,
         rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationPageParams {
             crate_name,
-            version,
+            version: VersionParams { version },
             path,
+            source: SourceParams { source },
+            max_length,
+            cursor,
+            format,
+            sections,
         }): rmcp::handler::server::wrapper::Parameters<RetrieveDocumentationPageParams>,
     ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
-        let response = self
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let mut contents = vec![Self::resolved_version_content(&resolved)];
+
+        match format.unwrap_or_default() {
+            PageFormat::Markdown => {
+                let response = self
+                    .docs_use_case
+                    .fetch_document_page(&resolved, &path)
+                    .await
+                    .map_err(|e| e.into())?;
+
+                contents.extend(self.markdown_contents(response, max_length, cursor)?);
+            }
+            PageFormat::Structured => {
+                let page = self
+                    .docs_use_case
+                    .fetch_item_page(&resolved, &path, &sections.unwrap_or_default())
+                    .await
+                    .map_err(|e| e.into())?;
+
+                contents.push(rmcp::model::Content::text(
+                    serde_json::to_string(&page).unwrap(),
+                ));
+            }
+        }
+
+        Ok(rmcp::model::CallToolResult::success(contents))
+    }
+
+    /// Retrieves a single member of an item page: a method, associated const or type, enum variant
+    /// or struct field, with its signature and full documentation.
+    /// Use this instead of retrieving the whole page when only one member is needed,
+    /// e.g. `path` `/vec/struct.Vec.html` with `member` `retain` for `Vec::retain`.
+    #[rmcp::tool]
+    async fn retrieve_documentation_member(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationMemberParams {
+            crate_name,
+            version: VersionParams { version },
+            path,
+            member,
+            source: SourceParams { source },
+        }): rmcp::handler::server::wrapper::Parameters<RetrieveDocumentationMemberParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let entity = self
             .docs_use_case
-            .fetch_document_page(&crate_name, &version, &path)
+            .fetch_item_member(&resolved, &path, &member)
             .await
             .map_err(|e| e.into())?;
 
-        let result = rmcp::model::Content::text(response);
+        let result = rmcp::model::Content::text(serde_json::to_string(&entity).unwrap());
 
-        Ok(rmcp::model::CallToolResult::success(vec![result]))
+        Ok(rmcp::model::CallToolResult::success(vec![
+            Self::resolved_version_content(&resolved),
+            result,
+        ]))
+    }
+
+    /// Retrieves the documentation of an item by its fully-qualified Rust path,
+    /// e.g. `tokio::sync::mpsc::Sender`, without knowing its page path.
+    /// Re-exported items are found under their re-exported path too.
+    /// When the path names a method, variant or field such as `std::vec::Vec::retain`,
+    /// only that member is returned.
+    /// The second entry of the result is the resolved page `path` for `retrieve_documentation_page`.
+    #[rmcp::tool]
+    async fn retrieve_documentation_by_path(
+        &self,
+        rmcp::handler::server::wrapper::Parameters(RetrieveDocumentationByPathParams {
+            rust_path,
+            crate_name,
+            version: VersionParams { version },
+            kind,
+            source: SourceParams { source },
+            max_length,
+            cursor,
+        }): rmcp::handler::server::wrapper::Parameters<RetrieveDocumentationByPathParams>,
+    ) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+        let crate_name = crate_name.unwrap_or_else(|| {
+            rust_path
+                .split("::")
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_owned()
+        });
+
+        let resolved = self
+            .resolve_version(&crate_name, version.as_deref(), source)
+            .await?;
+
+        let location = self
+            .docs_use_case
+            .locate_item(&resolved, &rust_path, kind.as_deref())
+            .await
+            .map_err(|e| e.into())?;
+
+        let mut contents = vec![
+            Self::resolved_version_content(&resolved),
+            rmcp::model::Content::text(serde_json::to_string(&location).unwrap()),
+        ];
+
+        match &location.member {
+            Some(member) => {
+                let entity = self
+                    .docs_use_case
+                    .fetch_item_member(&resolved, &location.path, member)
+                    .await
+                    .map_err(|e| e.into())?;
+
+                contents.push(rmcp::model::Content::text(
+                    serde_json::to_string(&entity).unwrap(),
+                ));
+            }
+            None => {
+                let response = self
+                    .docs_use_case
+                    .fetch_document_page(&resolved, &location.path)
+                    .await
+                    .map_err(|e| e.into())?;
+
+                contents.extend(self.markdown_contents(response, max_length, cursor)?);
+            }
+        }
+
+        Ok(rmcp::model::CallToolResult::success(contents))
     }
 }
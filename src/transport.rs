@@ -0,0 +1,77 @@
+/// Serves the handler over the configured transport until the client disconnects
+/// (`stdio`) or the process receives Ctrl-C (HTTP transports).
+///
+/// HTTP transports create one `Handler` per session by cloning `handler`,
+/// so all sessions share the same use cases and caches.
+pub async fn serve(
+    handler: crate::handler::Handler,
+    config: &crate::config::TransportConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.kind {
+        crate::config::TransportKind::Stdio => serve_stdio(handler).await,
+        crate::config::TransportKind::StreamableHttp => {
+            serve_streamable_http(handler, config.bind).await
+        }
+        crate::config::TransportKind::Sse => serve_sse(handler, config.bind).await,
+    }
+}
+
+async fn serve_stdio(handler: crate::handler::Handler) -> Result<(), Box<dyn std::error::Error>> {
+    use rmcp::ServiceExt;
+
+    let service = handler.serve(rmcp::transport::stdio()).await?;
+
+    service.waiting().await?;
+
+    Ok(())
+}
+
+async fn serve_streamable_http(
+    handler: crate::handler::Handler,
+    bind: std::net::SocketAddr,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = rmcp::transport::streamable_http_server::StreamableHttpService::new(
+        move || Ok(handler.clone()),
+        std::sync::Arc::new(
+            rmcp::transport::streamable_http_server::session::local::LocalSessionManager::default(),
+        ),
+        Default::default(),
+    );
+
+    let router = axum::Router::new().nest_service("/mcp", service);
+    let listener = tokio::net::TcpListener::bind(bind).await?;
+
+    tracing::info!("Serving MCP streamable HTTP on http://{}/mcp", bind);
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn serve_sse(
+    handler: crate::handler::Handler,
+    bind: std::net::SocketAddr,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = rmcp::transport::sse_server::SseServerConfig {
+        bind,
+        sse_path: "/sse".to_owned(),
+        post_path: "/message".to_owned(),
+        ct: tokio_util::sync::CancellationToken::new(),
+        sse_keep_alive: None,
+    };
+
+    let server = rmcp::transport::sse_server::SseServer::serve_with_config(config).await?;
+
+    tracing::info!("Serving MCP SSE on http://{}/sse", bind);
+
+    let ct = server.with_service(move || handler.clone());
+
+    let _ = tokio::signal::ctrl_c().await;
+    ct.cancel();
+
+    Ok(())
+}
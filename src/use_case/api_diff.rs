@@ -0,0 +1,230 @@
+/// Minimum name similarity for a removed and an added item of the same module to be
+/// reported as a rename rather than a removal and an addition.
+const RENAME_SIMILARITY: f32 = 0.6;
+
+/// Compares the items of two versions of a crate. Items are identified by their kind and
+/// path; signatures are only compared when both versions provide them.
+pub(super) fn diff_items(
+    old: Vec<crate::entity::docs::Item>,
+    new: Vec<crate::entity::docs::Item>,
+) -> crate::entity::docs::ApiDiff {
+    let key = |item: &crate::entity::docs::Item| (item.r#type.clone(), item.path.clone());
+
+    let mut old = old
+        .into_iter()
+        .map(|item| (key(&item), item))
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    let mut diff = crate::entity::docs::ApiDiff::default();
+    let mut added = Vec::new();
+
+    for new_item in new {
+        let Some(old_item) = old.remove(&key(&new_item)) else {
+            added.push(new_item);
+            continue;
+        };
+
+        if let (Some(old_signature), Some(new_signature)) =
+            (&old_item.signature, &new_item.signature)
+            && normalize_signature(old_signature) != normalize_signature(new_signature)
+        {
+            diff.signature_changed
+                .push(crate::entity::docs::SignatureChange {
+                    r#type: new_item.r#type.clone(),
+                    path: new_item.path.clone().unwrap_or_default(),
+                    old_signature: old_signature.clone(),
+                    new_signature: new_signature.clone(),
+                });
+        }
+
+        if old_item.deprecation.is_none() && new_item.deprecation.is_some() {
+            diff.deprecated.push(new_item);
+        }
+    }
+
+    let removed = old
+        .into_values()
+        .collect::<Vec<crate::entity::docs::Item>>();
+    let (renamed, removed, added) = match_renames(removed, added);
+
+    diff.added = added;
+    diff.removed = removed;
+    diff.renamed = renamed;
+
+    diff
+}
+
+/// Pairs removed items with added items of the same kind that look like the same item
+/// under a new name or in a new module, best matches first.
+fn match_renames(
+    removed: Vec<crate::entity::docs::Item>,
+    added: Vec<crate::entity::docs::Item>,
+) -> (
+    Vec<crate::entity::docs::RenamedItem>,
+    Vec<crate::entity::docs::Item>,
+    Vec<crate::entity::docs::Item>,
+) {
+    let mut candidates = Vec::new();
+
+    for (i, old) in removed.iter().enumerate() {
+        for (j, new) in added.iter().enumerate() {
+            if let Some(score) = rename_score(old, new) {
+                candidates.push((score, i, j));
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut pairs = Vec::new();
+    let mut removed_used = vec![false; removed.len()];
+    let mut added_used = vec![false; added.len()];
+
+    for (_, i, j) in candidates {
+        if !removed_used[i] && !added_used[j] {
+            removed_used[i] = true;
+            added_used[j] = true;
+            pairs.push((i, j));
+        }
+    }
+
+    let mut removed = removed.into_iter().map(Some).collect::<Vec<_>>();
+    let mut added = added.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut renamed = pairs
+        .into_iter()
+        .filter_map(|(i, j)| {
+            Some(crate::entity::docs::RenamedItem {
+                old: removed[i].take()?,
+                new: added[j].take()?,
+            })
+        })
+        .collect::<Vec<crate::entity::docs::RenamedItem>>();
+    renamed.sort_by(|a, b| a.old.path.cmp(&b.old.path));
+
+    (
+        renamed,
+        removed.into_iter().flatten().collect(),
+        added.into_iter().flatten().collect(),
+    )
+}
+
+/// How likely `new` is `old` renamed or moved, or `None` when they look unrelated.
+fn rename_score(old: &crate::entity::docs::Item, new: &crate::entity::docs::Item) -> Option<f32> {
+    if old.r#type != new.r#type {
+        return None;
+    }
+
+    let (old_path, new_path) = (old.path.as_deref()?, new.path.as_deref()?);
+    let (old_module, old_name) = old_path.rsplit_once("::").unwrap_or(("", old_path));
+    let (new_module, new_name) = new_path.rsplit_once("::").unwrap_or(("", new_path));
+
+    let same_docs = old.summary.is_some() && old.summary == new.summary;
+    let same_signature = match (&old.signature, &new.signature) {
+        (Some(old_signature), Some(new_signature)) => {
+            normalize_signature(&old_signature.replacen(old_name, "", 1))
+                == normalize_signature(&new_signature.replacen(new_name, "", 1))
+        }
+        _ => false,
+    };
+    let evidence = 0.1 * (f32::from(u8::from(same_docs)) + f32::from(u8::from(same_signature)));
+
+    if old_name == new_name {
+        // Moved to another module.
+        return Some(1.0 + evidence);
+    }
+
+    if old_module != new_module {
+        return None;
+    }
+
+    let similarity = super::search_index::name_similarity(old_name, new_name);
+    (similarity >= RENAME_SIMILARITY || same_docs).then_some(similarity + evidence)
+}
+
+fn normalize_signature(signature: &str) -> String {
+    signature
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    fn item(r#type: &str, path: &str, signature: Option<&str>) -> crate::entity::docs::Item {
+        crate::entity::docs::Item {
+            r#type: r#type.to_owned(),
+            path: Some(path.to_owned()),
+            signature: signature.map(str::to_owned),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_items() {
+        let old = vec![
+            item("Structs", "Client", None),
+            item("Structs", "ClientBuilder", None),
+            item("Structs", "Proxy", None),
+            item("Structs", "header::HeaderMap", None),
+            item("Structs", "UploadProgress", None),
+            item(
+                "Functions",
+                "get",
+                Some("pub async fn get<T: IntoUrl>(url: T) -> Result<Response>"),
+            ),
+            item(
+                "Functions",
+                "blocking_get",
+                Some("pub fn blocking_get(url: &str) -> Result<Response>"),
+            ),
+        ];
+
+        let mut deprecated = item("Structs", "Proxy", None);
+        deprecated.deprecation = Some(crate::entity::docs::Deprecation::default());
+
+        let new = vec![
+            item("Structs", "Client", None),
+            item("Structs", "ClientBuilder", None),
+            deprecated,
+            item("Structs", "http::HeaderMap", None),
+            item("Structs", "Upload", None),
+            item("Structs", "Body", None),
+            item(
+                "Functions",
+                "get",
+                Some("pub async fn get<U: IntoUrl>(url: U) -> Result<Response, Error>"),
+            ),
+        ];
+
+        let diff = super::diff_items(old, new);
+
+        let paths = |items: &[crate::entity::docs::Item]| {
+            items
+                .iter()
+                .map(|item| item.path.clone().unwrap())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(paths(&diff.added), vec!["Body"]);
+        assert_eq!(paths(&diff.removed), vec!["blocking_get"]);
+        assert_eq!(paths(&diff.deprecated), vec!["Proxy"]);
+
+        assert_eq!(
+            diff.renamed
+                .iter()
+                .map(|renamed| (
+                    renamed.old.path.as_deref().unwrap(),
+                    renamed.new.path.as_deref().unwrap()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("UploadProgress", "Upload"),
+                ("header::HeaderMap", "http::HeaderMap")
+            ]
+        );
+
+        assert_eq!(diff.signature_changed.len(), 1);
+        assert_eq!(diff.signature_changed[0].path, "get");
+    }
+}
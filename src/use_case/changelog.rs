@@ -0,0 +1,419 @@
+/// File names changelogs are published under, most common first.
+const CHANGELOG_NAMES: [&str; 6] = [
+    "changelog",
+    "changes",
+    "releases",
+    "release-notes",
+    "history",
+    "news",
+];
+
+const CHANGELOG_EXTENSIONS: [&str; 4] = ["md", "markdown", "txt", ""];
+
+/// Ranks a file name as a changelog, lower is better, or `None` when it is not one.
+fn changelog_rank(file_name: &str) -> Option<usize> {
+    let file_name = file_name.to_lowercase();
+    let (stem, extension) = file_name.rsplit_once('.').unwrap_or((&file_name, ""));
+
+    let name = CHANGELOG_NAMES
+        .iter()
+        .position(|name| stem.replace('_', "-") == *name)?;
+    let extension = CHANGELOG_EXTENSIONS
+        .iter()
+        .position(|candidate| extension == *candidate)?;
+
+    Some(name * CHANGELOG_EXTENSIONS.len() + extension)
+}
+
+/// Finds the changelog at the root of a packaged `.crate` archive and returns its path
+/// inside the archive with its content.
+pub(super) fn find_in_crate(
+    archive: &[u8],
+) -> Result<Option<(String, String)>, crate::error::Error> {
+    use std::io::Read;
+
+    let to_error = |e: std::io::Error| {
+        tracing::error!("{}", e);
+        crate::error::Error::Changelog(e.to_string())
+    };
+
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(archive));
+    let mut best: Option<(usize, String, String)> = None;
+
+    for entry in archive.entries().map_err(to_error)? {
+        let mut entry = entry.map_err(to_error)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        // Packaged files live in a single `{name}-{version}/` directory.
+        let path = entry
+            .path()
+            .map_err(to_error)?
+            .to_string_lossy()
+            .into_owned();
+        let Some((_, file_name)) = path.split_once('/') else {
+            continue;
+        };
+        if file_name.contains('/') {
+            continue;
+        }
+
+        let Some(rank) = changelog_rank(file_name) else {
+            continue;
+        };
+        if best.as_ref().is_some_and(|(best, _, _)| *best <= rank) {
+            continue;
+        }
+
+        let mut content = String::new();
+        if entry.read_to_string(&mut content).is_ok() {
+            best = Some((rank, file_name.to_owned(), content));
+        }
+    }
+
+    Ok(best.map(|(_, path, content)| (path, content)))
+}
+
+/// Raw file URLs a changelog may be found at in a GitHub or GitLab repository, trying the
+/// crate's own directory first since workspaces often keep one changelog per crate.
+pub(super) fn repository_urls(repository: &str, crate_name: &str) -> Vec<String> {
+    let repository = repository.trim().trim_end_matches('/');
+    let Some((host, rest)) = repository
+        .strip_prefix("https://")
+        .or_else(|| repository.strip_prefix("http://"))
+        .and_then(|rest| rest.split_once('/'))
+    else {
+        return Vec::new();
+    };
+
+    let mut segments = rest.split('/');
+    let (Some(owner), Some(repo)) = (segments.next(), segments.next()) else {
+        return Vec::new();
+    };
+    let repo = repo.trim_end_matches(".git");
+
+    // `https://github.com/owner/repo/tree/master/crates/foo` points at the crate directory.
+    let subdir = match (segments.next(), segments.next()) {
+        (Some("tree"), Some(_)) => Some(segments.collect::<Vec<&str>>().join("/")),
+        _ => None,
+    };
+
+    let raw_base = match host.trim_start_matches("www.") {
+        "github.com" => format!("https://raw.githubusercontent.com/{owner}/{repo}/HEAD"),
+        "gitlab.com" => format!("https://gitlab.com/{owner}/{repo}/-/raw/HEAD"),
+        _ => return Vec::new(),
+    };
+    let raw_base = raw_base.as_str();
+
+    let mut directories = Vec::new();
+    directories.extend(subdir.filter(|subdir| !subdir.is_empty()));
+    directories.push(crate_name.to_owned());
+    directories.push(String::new());
+    directories.dedup();
+
+    directories
+        .iter()
+        .flat_map(|directory| {
+            ["CHANGELOG.md", "CHANGES.md", "RELEASES.md"]
+                .into_iter()
+                .map(move |file_name| match directory.as_str() {
+                    "" => format!("{raw_base}/{file_name}"),
+                    directory => format!("{raw_base}/{directory}/{file_name}"),
+                })
+        })
+        .collect()
+}
+
+/// Splits a markdown changelog into one section per version heading. Version headings
+/// are the headings at the level most versions are mentioned at, so that headings such as
+/// `### Fixed` or `#### MSRV raised to 1.70` inside a release stay part of it. Changelogs
+/// without headings may list releases as top-level bullets such as `- 2.2.2` instead.
+pub(super) fn parse_sections(markdown: &str) -> Vec<crate::entity::docs::ChangelogSection> {
+    // Bullets rank below every heading level.
+    const BULLET_LEVEL: usize = 7;
+
+    let lines = markdown.lines().collect::<Vec<&str>>();
+
+    let mut headings = Vec::new();
+    let mut bullets = Vec::new();
+    let mut in_code = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+
+        if let Some(level) = atx_level(line) {
+            headings.push((i, i + 1, level, line[level..].trim().trim_end_matches('#')));
+        } else if let Some(text) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| line.strip_prefix(bullet))
+        {
+            bullets.push((i, i + 1, BULLET_LEVEL, text.trim()));
+        } else if i > 0 && !lines[i - 1].trim().is_empty() && atx_level(lines[i - 1]).is_none() {
+            // Setext headings are underlined with `===` or `---`.
+            let level = match line.trim_end() {
+                underline if underline.len() >= 3 && underline.chars().all(|c| c == '=') => 1,
+                underline if underline.len() >= 3 && underline.chars().all(|c| c == '-') => 2,
+                _ => continue,
+            };
+            headings.push((i - 1, i + 1, level, lines[i - 1].trim()));
+        }
+    }
+
+    if !headings
+        .iter()
+        .any(|(_, _, _, text)| heading_version(text).is_some())
+    {
+        headings = bullets;
+    }
+
+    let mut level_counts = [0; BULLET_LEVEL + 1];
+    for (_, _, level, text) in &headings {
+        if heading_version(text).is_some() {
+            level_counts[*level] += 1;
+        }
+    }
+    let Some(section_level) = (1..=BULLET_LEVEL)
+        .filter(|level| level_counts[*level] > 0)
+        .max_by_key(|level| (level_counts[*level], std::cmp::Reverse(*level)))
+    else {
+        return Vec::new();
+    };
+
+    let mut sections = Vec::new();
+
+    for (n, (_, body_start, level, text)) in headings.iter().enumerate() {
+        if *level > section_level {
+            continue;
+        }
+        let Some(version) = heading_version(text) else {
+            continue;
+        };
+
+        let end = headings[n + 1..]
+            .iter()
+            .find(|(_, _, next_level, _)| *next_level <= section_level)
+            .map_or(lines.len(), |(start, _, _, _)| *start);
+
+        sections.push(crate::entity::docs::ChangelogSection {
+            version: version.to_string(),
+            date: heading_date(text),
+            notes: lines[*body_start..end].join("\n").trim().to_owned(),
+        });
+    }
+
+    sections
+}
+
+fn atx_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with([' ', '\t'])))
+        .then_some(level)
+}
+
+/// First version mentioned in a heading such as `## [1.2.3] - 2024-01-31`,
+/// `# Tokio 1.38.0 (May 30th, 2024)` or `## tokio-util-0.7`.
+fn heading_version(heading: &str) -> Option<semver::Version> {
+    heading
+        .split(|c: char| c.is_whitespace() || "[]()<>,:;*_`/".contains(c))
+        .find_map(|token| {
+            let token = token.trim_matches(|c: char| c == '.' || c == '"' || c == '\'');
+            let candidates = [
+                Some(token),
+                token.rsplit_once('-').map(|(_, version)| version),
+            ];
+
+            candidates.into_iter().flatten().find_map(|candidate| {
+                let candidate = candidate.trim_start_matches(['v', 'V']);
+                semver::Version::parse(candidate).ok().or_else(|| {
+                    // `0.12` is shorthand for `0.12.0`.
+                    let (major, minor) = candidate.split_once('.')?;
+                    [major, minor]
+                        .iter()
+                        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+                        .then(|| semver::Version::parse(&format!("{candidate}.0")).ok())?
+                })
+            })
+        })
+}
+
+/// ISO date in a heading, e.g. `2024-01-31`.
+fn heading_date(heading: &str) -> Option<String> {
+    heading
+        .split(|c: char| !(c.is_ascii_digit() || c == '-'))
+        .find(|token| {
+            token.len() == 10
+                && token.char_indices().all(|(i, c)| match i {
+                    4 | 7 => c == '-',
+                    _ => c.is_ascii_digit(),
+                })
+        })
+        .map(str::to_owned)
+}
+
+/// Keeps the sections of versions after `from` up to and including `to`, i.e. the changes
+/// an upgrade from `from` to `to` brings. When `from` is not older than `to`, only the
+/// section of `to` is kept.
+pub(super) fn select_sections(
+    sections: Vec<crate::entity::docs::ChangelogSection>,
+    from: &semver::Version,
+    to: &semver::Version,
+) -> Vec<crate::entity::docs::ChangelogSection> {
+    sections
+        .into_iter()
+        .filter(|section| {
+            let Ok(version) = semver::Version::parse(&section.version) else {
+                return false;
+            };
+            if from < to {
+                *from < version && version <= *to
+            } else {
+                version == *to
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    const CHANGELOG: &str = r#"# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [Unreleased]
+
+- Nothing yet.
+
+## [0.12.1] - 2024-03-20
+
+### Fixed
+
+- Fix `Client::get` with relative URLs.
+
+#### MSRV raised to 1.70
+
+```text
+## 9.9.9 is not a heading
+```
+
+## [v0.12.0] - 2024-03-01
+
+- Upgrade to `hyper` 1.0.
+
+v0.11.27
+--------
+
+- Add `ClientBuilder::tls_info`.
+"#;
+
+    #[test]
+    fn test_parse_sections() {
+        let sections = super::parse_sections(CHANGELOG);
+
+        assert_eq!(
+            sections
+                .iter()
+                .map(|section| (section.version.as_str(), section.date.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("0.12.1", Some("2024-03-20")),
+                ("0.12.0", Some("2024-03-01")),
+                ("0.11.27", None),
+            ]
+        );
+        assert!(sections[0].notes.starts_with("### Fixed"));
+        assert!(sections[0].notes.contains("9.9.9"));
+        assert_eq!(sections[2].notes, "- Add `ClientBuilder::tls_info`.");
+
+        assert_eq!(
+            super::heading_version("Tokio 1.38.0 (May 30th, 2024)"),
+            semver::Version::parse("1.38.0").ok()
+        );
+        assert_eq!(
+            super::heading_version("tokio-util-0.7"),
+            semver::Version::parse("0.7.0").ok()
+        );
+
+        // Releases listed as bullets, and a newest heading one level above the others.
+        let sections = super::parse_sections("- 2.2.2\n\n  - Added raw entries.\n\n- 2.2.1\n");
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].notes, "- Added raw entries.");
+
+        let sections = super::parse_sections("# 2.1.1\n\nA\n\n## 2.1.0\n\nB\n\n## 2.0.0\n");
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].notes, "A");
+
+        let version = |version| semver::Version::parse(version).unwrap();
+
+        let sections = super::parse_sections(CHANGELOG);
+
+        let selected =
+            super::select_sections(sections.clone(), &version("0.11.27"), &version("0.12.1"));
+        assert_eq!(selected.len(), 2);
+
+        let selected = super::select_sections(sections, &version("0.12.1"), &version("0.12.1"));
+        assert_eq!(selected[0].version, "0.12.1");
+    }
+
+    #[test]
+    fn test_find_in_crate() -> Result<(), crate::error::Error> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        for (path, content) in [
+            ("demo-1.0.0/src/CHANGELOG.md", "nested"),
+            ("demo-1.0.0/HISTORY.txt", "history"),
+            ("demo-1.0.0/CHANGELOG.md", "# Changelog"),
+            ("demo-1.0.0/README.md", "readme"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, content.as_bytes())
+                .unwrap();
+        }
+        let archive = builder.into_inner().unwrap().finish().unwrap();
+
+        assert_eq!(
+            super::find_in_crate(&archive)?,
+            Some(("CHANGELOG.md".to_owned(), "# Changelog".to_owned()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repository_urls() {
+        let urls = super::repository_urls("https://github.com/tokio-rs/tokio.git", "tokio");
+        assert_eq!(
+            urls[0],
+            "https://raw.githubusercontent.com/tokio-rs/tokio/HEAD/tokio/CHANGELOG.md"
+        );
+        assert!(urls.contains(
+            &"https://raw.githubusercontent.com/tokio-rs/tokio/HEAD/CHANGELOG.md".to_owned()
+        ));
+
+        let urls = super::repository_urls(
+            "https://github.com/rust-lang/futures-rs/tree/master/futures-util",
+            "futures-util",
+        );
+        assert_eq!(
+            urls[0],
+            "https://raw.githubusercontent.com/rust-lang/futures-rs/HEAD/futures-util/CHANGELOG.md"
+        );
+
+        assert!(super::repository_urls("https://example.com/repo", "demo").is_empty());
+    }
+}
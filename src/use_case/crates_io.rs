@@ -7,10 +7,17 @@ pub struct CratesIoUseCase {
 impl CratesIoUseCase {
     pub async fn search_crate(
         &self,
-        keyword: &str,
-    ) -> Result<Vec<crate::entity::crates_io::CrateSummaryEntity>, crate::error::Error> {
-        let crates = self.crates_io_repository.search_crate(keyword).await?;
-        let entities = crates
+        query: crate::record::crates_io::CrateSearchQuery,
+    ) -> Result<crate::entity::crates_io::CrateSearchEntity, crate::error::Error> {
+        let query = crate::record::crates_io::CrateSearchQuery {
+            page: query.page.max(1),
+            per_page: query.per_page.clamp(1, 100),
+            ..query
+        };
+
+        let record = self.crates_io_repository.search_crate(&query).await?;
+        let crates = record
+            .crates
             .into_iter()
             .map(|c| crate::entity::crates_io::CrateSummaryEntity {
                 name: c.name,
@@ -23,6 +30,297 @@ impl CratesIoUseCase {
             })
             .collect::<Vec<crate::entity::crates_io::CrateSummaryEntity>>();
 
-        Ok(entities)
+        let next_page = (query.page * query.per_page < record.total).then_some(query.page + 1);
+
+        Ok(crate::entity::crates_io::CrateSearchEntity {
+            total: record.total,
+            page: query.page,
+            per_page: query.per_page,
+            next_page,
+            crates,
+        })
+    }
+
+    pub async fn get_crate(
+        &self,
+        crate_name: &str,
+    ) -> Result<crate::entity::crates_io::CrateDetailEntity, crate::error::Error> {
+        let c = self.crates_io_repository.get_crate(crate_name).await?;
+
+        let entity = crate::entity::crates_io::CrateDetailEntity {
+            name: c.name,
+            description: c.description,
+            repository: c.repository,
+            homepage: c.homepage,
+            documentation: c.documentation,
+            license: c.license,
+            keywords: c.keywords,
+            categories: c.categories,
+            rust_version: c.rust_version,
+            edition: c.edition,
+            owners: c
+                .owners
+                .into_iter()
+                .map(|o| crate::entity::crates_io::CrateOwnerEntity {
+                    login: o.login,
+                    name: o.name,
+                    kind: o.kind,
+                    url: o.url,
+                })
+                .collect::<Vec<crate::entity::crates_io::CrateOwnerEntity>>(),
+            downloads: c.downloads,
+            recent_downloads: c.recent_downloads,
+            latest_stable_version: c.latest_stable_version,
+            latest_version: c.latest_version,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+            versions: c
+                .versions
+                .into_iter()
+                .map(|v| crate::entity::crates_io::CrateVersionEntity {
+                    num: v.num,
+                    yanked: v.yanked,
+                    created_at: v.created_at,
+                    downloads: v.downloads,
+                    license: v.license,
+                    rust_version: v.rust_version,
+                })
+                .collect::<Vec<crate::entity::crates_io::CrateVersionEntity>>(),
+        };
+
+        Ok(entity)
+    }
+
+    pub async fn get_dependencies(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<crate::entity::crates_io::CrateDependenciesEntity, crate::error::Error> {
+        let record = self
+            .crates_io_repository
+            .get_dependencies(crate_name, version)
+            .await?;
+
+        let features = with_implicit_features(record.features, &record.dependencies);
+
+        let default_features = enabled_features("default", &features);
+
+        let mut feature_entities = features
+            .iter()
+            .map(|(name, enables)| crate::entity::crates_io::FeatureEntity {
+                name: name.clone(),
+                enables: enables.clone(),
+                optional_dependencies: enabled_optional_dependencies(name, &features)
+                    .into_iter()
+                    .collect(),
+                default: default_features.contains(name),
+                implicit: enables.len() == 1 && enables[0] == format!("dep:{name}"),
+            })
+            .collect::<Vec<crate::entity::crates_io::FeatureEntity>>();
+        feature_entities.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut dependencies = record
+            .dependencies
+            .into_iter()
+            .map(|d| crate::entity::crates_io::DependencyEntity {
+                enabled_by_features: if d.optional {
+                    feature_entities
+                        .iter()
+                        .filter(|f| f.optional_dependencies.contains(&d.name))
+                        .map(|f| f.name.clone())
+                        .collect()
+                } else {
+                    Vec::new()
+                },
+                name: d.name,
+                req: d.req,
+                kind: d.kind,
+                optional: d.optional,
+                default_features: d.default_features,
+                features: d.features,
+                target: d.target,
+            })
+            .collect::<Vec<crate::entity::crates_io::DependencyEntity>>();
+        dependencies.sort_by(|a, b| (&a.kind, &a.name).cmp(&(&b.kind, &b.name)));
+
+        Ok(crate::entity::crates_io::CrateDependenciesEntity {
+            name: crate_name.to_owned(),
+            version: version.to_owned(),
+            dependencies,
+            features: feature_entities,
+        })
+    }
+
+    pub async fn reverse_dependencies(
+        &self,
+        crate_name: &str,
+        page: u64,
+        per_page: u64,
+        sort: ReverseDependencySort,
+    ) -> Result<crate::entity::crates_io::ReverseDependenciesEntity, crate::error::Error> {
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, 100);
+
+        let record = self
+            .crates_io_repository
+            .reverse_dependencies(crate_name, page, per_page)
+            .await?;
+
+        let mut dependents = record
+            .dependencies
+            .into_iter()
+            .map(|d| crate::entity::crates_io::ReverseDependencyEntity {
+                crate_name: d.crate_name,
+                version: d.version,
+                req: d.req,
+                kind: d.kind,
+                optional: d.optional,
+                downloads: d.downloads,
+            })
+            .collect::<Vec<crate::entity::crates_io::ReverseDependencyEntity>>();
+
+        // crates.io already orders dependents by their total downloads.
+        if sort == ReverseDependencySort::Name {
+            dependents.sort_by(|a, b| a.crate_name.cmp(&b.crate_name));
+        }
+
+        Ok(crate::entity::crates_io::ReverseDependenciesEntity {
+            name: crate_name.to_owned(),
+            total: record.total,
+            page,
+            per_page,
+            dependents,
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, rmcp::schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReverseDependencySort {
+    /// Most downloaded dependent crates first.
+    #[default]
+    Downloads,
+
+    /// Alphabetical by dependent crate name, within the requested page.
+    Name,
+}
+
+/// Adds the implicit feature Cargo creates for every optional dependency
+/// that is never referenced with the `dep:` syntax.
+fn with_implicit_features(
+    mut features: std::collections::BTreeMap<String, Vec<String>>,
+    dependencies: &[crate::record::crates_io::DependencyRecord],
+) -> std::collections::BTreeMap<String, Vec<String>> {
+    let explicit = features
+        .values()
+        .flatten()
+        .filter_map(|value| value.strip_prefix("dep:"))
+        .map(str::to_owned)
+        .collect::<std::collections::BTreeSet<String>>();
+
+    for dependency in dependencies.iter().filter(|d| d.optional) {
+        if !explicit.contains(&dependency.name) {
+            features
+                .entry(dependency.name.clone())
+                .or_insert_with(|| vec![format!("dep:{}", dependency.name)]);
+        }
+    }
+
+    features
+}
+
+/// Returns `feature` and every feature it enables transitively.
+fn enabled_features(
+    feature: &str,
+    features: &std::collections::BTreeMap<String, Vec<String>>,
+) -> std::collections::BTreeSet<String> {
+    let mut enabled = std::collections::BTreeSet::new();
+    let mut stack = vec![feature.to_owned()];
+
+    while let Some(feature) = stack.pop() {
+        let Some(values) = features.get(&feature) else {
+            continue;
+        };
+        if !enabled.insert(feature) {
+            continue;
+        }
+        for value in values {
+            if value.starts_with("dep:") {
+                continue;
+            }
+            // `foo/bar` enables the feature `foo` as well, `foo?/bar` does not.
+            match value.split_once('/') {
+                Some((name, _)) if !name.ends_with('?') => stack.push(name.to_owned()),
+                Some(_) => {}
+                None => stack.push(value.clone()),
+            }
+        }
+    }
+
+    enabled
+}
+
+/// Returns the optional dependencies that `feature` turns on, directly or through other features.
+fn enabled_optional_dependencies(
+    feature: &str,
+    features: &std::collections::BTreeMap<String, Vec<String>>,
+) -> std::collections::BTreeSet<String> {
+    enabled_features(feature, features)
+        .iter()
+        .filter_map(|feature| features.get(feature))
+        .flatten()
+        .filter_map(|value| {
+            if let Some(dependency) = value.strip_prefix("dep:") {
+                return Some(dependency.to_owned());
+            }
+            match value.split_once('/') {
+                Some((dependency, _)) if !dependency.ends_with('?') => {
+                    let implicit = features.get(dependency)?;
+                    implicit
+                        .iter()
+                        .any(|v| v == &format!("dep:{dependency}"))
+                        .then(|| dependency.to_owned())
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_enabled_optional_dependencies() {
+        let dependencies = ["serde", "tokio", "tracing"]
+            .into_iter()
+            .map(|name| crate::record::crates_io::DependencyRecord {
+                name: name.to_owned(),
+                optional: true,
+                ..Default::default()
+            })
+            .collect::<Vec<crate::record::crates_io::DependencyRecord>>();
+
+        let features = [
+            ("default", vec!["full"]),
+            ("full", vec!["rt", "serde"]),
+            ("rt", vec!["dep:tokio", "tracing?/std"]),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_owned(), v.into_iter().map(str::to_owned).collect()))
+        .collect();
+
+        let features = super::with_implicit_features(features, &dependencies);
+
+        assert_eq!(features["serde"], vec!["dep:serde"]);
+        assert!(!features.contains_key("tokio"));
+        assert_eq!(
+            super::enabled_optional_dependencies("default", &features)
+                .into_iter()
+                .collect::<Vec<String>>(),
+            vec!["serde", "tokio"]
+        );
+        assert!(super::enabled_optional_dependencies("tracing", &features).contains("tracing"));
     }
 }
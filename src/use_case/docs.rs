@@ -1,17 +1,339 @@
-use tantivy::schema::Value;
+/// Non-yanked versions of each crate, keyed by crate name, with the time they were fetched.
+pub type VersionCache = std::sync::Arc<
+    std::sync::Mutex<std::collections::HashMap<String, (std::time::Instant, Vec<semver::Version>)>>,
+>;
 
 #[derive(Debug, Clone)]
 pub struct DocsUseCase {
     pub http_repository: std::sync::Arc<dyn crate::repository::http::HttpRepository + Send + Sync>,
+    pub file_repository: std::sync::Arc<dyn crate::repository::file::FileRepository + Send + Sync>,
+    pub crates_io_repository:
+        std::sync::Arc<dyn crate::repository::crates_io::CratesIoRepository + Send + Sync>,
+    pub search_index_cache: std::sync::Arc<crate::use_case::search_index::SearchIndexCache>,
+    pub text_index_cache: std::sync::Arc<
+        crate::use_case::search_index::SearchIndexCache<crate::use_case::text_index::TextIndex>,
+    >,
+    pub version_cache: VersionCache,
+    pub local_docs: crate::config::LocalDocsConfig,
+    pub std_docs: crate::config::StdDocsConfig,
+}
+
+/// Root of the documentation of one crate version, which pages are resolved against.
+#[derive(Debug, Clone)]
+enum DocsRoot {
+    /// Base URL without a trailing slash, e.g. `https://docs.rs/serde/1.0.228/serde`.
+    Remote(String),
+
+    /// Directory of the crate inside a `target/doc` directory.
+    Local(std::path::PathBuf),
 }
 
 impl DocsUseCase {
+    const VERSION_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+    /// Upper bound of the pages crawled for a full-text index, so that a single search
+    /// of a huge crate does not turn into thousands of requests.
+    const TEXT_INDEX_MAX_PAGES: usize = 1_000;
+
+    /// Pages fetched in parallel while crawling for a full-text index.
+    const TEXT_INDEX_CONCURRENCY: usize = 8;
+
+    /// Crates shipped with the toolchain, which docs.rs does not host.
+    pub(super) const STD_CRATES: [&'static str; 4] = ["std", "core", "alloc", "proc_macro"];
+
+    async fn list_versions(
+        &self,
+        crate_name: &str,
+    ) -> Result<Vec<semver::Version>, crate::error::Error> {
+        if let Some((fetched_at, versions)) = self
+            .version_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(crate_name)
+            && fetched_at.elapsed() < Self::VERSION_CACHE_TTL
+        {
+            return Ok(versions.clone());
+        }
+
+        let versions = self
+            .crates_io_repository
+            .list_versions(crate_name)
+            .await?
+            .into_iter()
+            .filter(|v| !v.yanked)
+            .filter_map(|v| semver::Version::parse(&v.num).ok())
+            .collect::<Vec<semver::Version>>();
+
+        self.version_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                crate_name.to_owned(),
+                (std::time::Instant::now(), versions.clone()),
+            );
+
+        Ok(versions)
+    }
+
+    /// Resolves `latest`, an exact version, or a semver requirement such as `^1.2` or `1`
+    /// to a concrete, non-yanked version published on crates.io.
+    ///
+    /// Exact versions are returned as-is without querying crates.io.
+    /// Local documentation is unversioned and always resolves to `local`,
+    /// toolchain documentation always resolves to `toolchain`.
+    pub async fn resolve_version(
+        &self,
+        crate_name: &str,
+        version: &str,
+        source: Option<crate::entity::docs::DocsSource>,
+    ) -> Result<crate::entity::docs::ResolvedVersion, crate::error::Error> {
+        let requested = version.trim();
+        let source = source.unwrap_or_else(|| self.default_source(crate_name));
+
+        let resolved = |resolved_version: String| crate::entity::docs::ResolvedVersion {
+            crate_name: crate_name.to_owned(),
+            requested_version: requested.to_owned(),
+            resolved_version,
+            source,
+        };
+
+        match source {
+            crate::entity::docs::DocsSource::Local => return Ok(resolved("local".to_owned())),
+            crate::entity::docs::DocsSource::Toolchain => {
+                return Ok(resolved("toolchain".to_owned()));
+            }
+            crate::entity::docs::DocsSource::RustLang => {
+                return Self::resolve_toolchain(requested).map(resolved);
+            }
+            crate::entity::docs::DocsSource::DocsRs => {}
+        }
+
+        if let Ok(exact) = semver::Version::parse(requested.strip_prefix('=').unwrap_or(requested))
+        {
+            return Ok(resolved(exact.to_string()));
+        }
+
+        let is_latest = matches!(requested, "" | "latest" | "*");
+
+        let versions = match self.list_versions(crate_name).await {
+            Ok(versions) => versions,
+            Err(e) if is_latest => {
+                // docs.rs can still resolve `latest` on its own, it is just not reproducible.
+                tracing::warn!("Could not resolve latest version of {crate_name}: {e}");
+                return Ok(resolved("latest".to_owned()));
+            }
+            Err(e) => return Err(e),
+        };
+
+        let version = if is_latest {
+            versions
+                .iter()
+                .filter(|v| v.pre.is_empty())
+                .max()
+                .or_else(|| versions.iter().max())
+        } else {
+            let requirement = semver::VersionReq::parse(requested).map_err(|e| {
+                crate::error::Error::VersionResolution(format!(
+                    "Invalid version requirement `{requested}`: {e}"
+                ))
+            })?;
+
+            versions.iter().filter(|v| requirement.matches(v)).max()
+        };
+
+        version
+            .map(|version| resolved(version.to_string()))
+            .ok_or_else(|| {
+                crate::error::Error::VersionResolution(format!(
+                    "No published version of {crate_name} matches `{requested}`"
+                ))
+            })
+    }
+
+    /// Resolves the version of a standard library crate to a release channel
+    /// (`stable`, `beta`, `nightly`) or a Rust release such as `1.80.0`.
+    fn resolve_toolchain(requested: &str) -> Result<String, crate::error::Error> {
+        let requested = requested.strip_prefix('=').unwrap_or(requested);
+
+        match requested {
+            "" | "latest" | "*" => return Ok("stable".to_owned()),
+            "stable" | "beta" | "nightly" => return Ok(requested.to_owned()),
+            _ => {}
+        }
+
+        // Rust releases are commonly referred to without the patch version, e.g. `1.80`.
+        semver::Version::parse(requested)
+            .or_else(|_| semver::Version::parse(&format!("{requested}.0")))
+            .map(|version| version.to_string())
+            .map_err(|_| {
+                crate::error::Error::VersionResolution(format!(
+                    "Unknown Rust toolchain `{requested}`. Use `stable`, `beta`, `nightly` or a release such as `1.80.0`"
+                ))
+            })
+    }
+
+    /// The standard library is read from doc.rust-lang.org (or the installed toolchain when
+    /// preferred), crates configured as local from `target/doc`, and everything else from docs.rs.
+    fn default_source(&self, crate_name: &str) -> crate::entity::docs::DocsSource {
+        if Self::STD_CRATES.contains(&crate_name) {
+            return if self.std_docs.prefer_toolchain && self.std_docs.toolchain_dir.is_some() {
+                crate::entity::docs::DocsSource::Toolchain
+            } else {
+                crate::entity::docs::DocsSource::RustLang
+            };
+        }
+
+        let is_local = self
+            .local_docs
+            .crates
+            .iter()
+            .any(|local| local.replace('-', "_") == crate_name.replace('-', "_"));
+
+        if is_local {
+            crate::entity::docs::DocsSource::Local
+        } else {
+            crate::entity::docs::DocsSource::DocsRs
+        }
+    }
+
+    fn docs_root(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<DocsRoot, crate::error::Error> {
+        let crate_name = &target.crate_name;
+        let version = &target.resolved_version;
+
+        match target.source {
+            crate::entity::docs::DocsSource::DocsRs => Ok(DocsRoot::Remote(format!(
+                "https://docs.rs/{crate_name}/{version}/{crate_name}"
+            ))),
+            crate::entity::docs::DocsSource::RustLang => Ok(DocsRoot::Remote(format!(
+                "https://doc.rust-lang.org/{version}/{crate_name}"
+            ))),
+            crate::entity::docs::DocsSource::Local => {
+                Ok(DocsRoot::Local(self.local_crate_dir(crate_name)?))
+            }
+            crate::entity::docs::DocsSource::Toolchain => {
+                Ok(DocsRoot::Local(self.toolchain_crate_dir(crate_name)?))
+            }
+        }
+    }
+
+    fn local_crate_dir(&self, crate_name: &str) -> Result<std::path::PathBuf, crate::error::Error> {
+        let dir = self.local_docs.dir.as_ref().ok_or_else(|| {
+            crate::error::Error::LocalDocs("MCP_RUST_DOCS_LOCAL_DOC_DIR is not set".to_owned())
+        })?;
+
+        // rustdoc replaces dashes in crate names with underscores.
+        Ok(dir.join(crate_name.replace('-', "_")))
+    }
+
+    fn toolchain_crate_dir(
+        &self,
+        crate_name: &str,
+    ) -> Result<std::path::PathBuf, crate::error::Error> {
+        let dir = self.std_docs.toolchain_dir.as_ref().ok_or_else(|| {
+            crate::error::Error::LocalDocs(
+                "Toolchain documentation not found. Install it with `rustup component add rust-docs` or set MCP_RUST_DOCS_TOOLCHAIN_DOC_DIR".to_owned(),
+            )
+        })?;
+
+        Ok(dir.join(crate_name))
+    }
+
+    /// Absolute URL of the page at `path`, which links on the page are resolved against.
+    fn page_url(&self, root: &DocsRoot, path: &str) -> Option<reqwest::Url> {
+        match root {
+            DocsRoot::Remote(base) => reqwest::Url::parse(&format!("{base}{path}")).ok(),
+            DocsRoot::Local(dir) => {
+                let dir = std::path::absolute(dir).ok()?;
+                let url = reqwest::Url::from_directory_path(dir).ok()?;
+                url.join(path.trim_start_matches('/')).ok()
+            }
+        }
+    }
+
+    /// Makes the links of a converted page absolute and annotates them for the docs tools.
+    fn rewrite_links(&self, markdown: &str, root: &DocsRoot, path: &str) -> String {
+        let Some(page_url) = self.page_url(root, path) else {
+            return markdown.to_owned();
+        };
+
+        let local = self
+            .local_docs
+            .dir
+            .as_deref()
+            .and_then(|dir| std::path::absolute(dir).ok());
+        let toolchain = self
+            .std_docs
+            .toolchain_dir
+            .as_deref()
+            .and_then(|dir| std::path::absolute(dir).ok());
+        let roots = super::links::LocalRoots {
+            local: local.as_deref(),
+            toolchain: toolchain.as_deref(),
+        };
+
+        super::links::rewrite_links(markdown, &page_url, &roots)
+    }
+
+    /// Fetches the page at `path` (e.g. `/de/index.html`) relative to the documentation root.
+    async fn fetch_html(&self, root: &DocsRoot, path: &str) -> Result<String, crate::error::Error> {
+        match root {
+            DocsRoot::Remote(base) => self.http_repository.get(&format!("{base}{path}")).await,
+            DocsRoot::Local(dir) => {
+                let path = path
+                    .split(['#', '?'])
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches('/');
+
+                let relative = std::path::Path::new(path);
+                if relative
+                    .components()
+                    .any(|c| !matches!(c, std::path::Component::Normal(_)))
+                {
+                    return Err(crate::error::Error::LocalDocs(format!(
+                        "Invalid documentation path: {path}"
+                    )));
+                }
+
+                self.file_repository
+                    .read_to_string(&dir.join(relative))
+                    .await
+            }
+        }
+    }
+
+    /// Fetches a file at the doc root shared by all crates of a build, such as `search-index.js`.
+    async fn fetch_root_file(
+        &self,
+        root: &DocsRoot,
+        file: &str,
+    ) -> Result<String, crate::error::Error> {
+        match root {
+            DocsRoot::Remote(base) => {
+                let doc_root = base
+                    .rsplit_once('/')
+                    .map_or(base.as_str(), |(root, _)| root);
+                self.http_repository
+                    .get(&format!("{doc_root}/{file}"))
+                    .await
+            }
+            DocsRoot::Local(dir) => {
+                let doc_root = dir.parent().unwrap_or(dir).to_owned();
+                self.fetch_html(&DocsRoot::Local(doc_root), &format!("/{file}"))
+                    .await
+            }
+        }
+    }
+
     pub(super) fn extract_main_content(
         &self,
         html: &str,
         selector: &str,
     ) -> Result<String, crate::error::Error> {
-        let document = scraper::Html::parse_document(&html);
+        let document = scraper::Html::parse_document(html);
 
         let selector = scraper::Selector::parse(selector).map_err(|e| {
             tracing::error!("{} This error is due to a static selector configuration mistake on the crate side. Please create an issue if necessary.", e.to_string());
@@ -20,11 +342,11 @@ impl DocsUseCase {
 
         let contents = document.select(&selector);
 
-        let mut iter = contents.into_iter();
+        let mut iter = contents;
 
         if let Some(first) = iter.next() {
             let html = first.inner_html().to_string();
-            return Ok(html);
+            Ok(html)
         } else {
             Err(crate::error::Error::HtmlMainContentNotFound(String::from(
                 "Element not found: section#main-content",
@@ -34,31 +356,178 @@ impl DocsUseCase {
 
     pub async fn fetch_document_index_page(
         &self,
-        crate_name: &str,
-        version: &str,
+        target: &crate::entity::docs::ResolvedVersion,
     ) -> Result<String, crate::error::Error> {
-        let url = format!("https://docs.rs/{crate_name}/{version}/{crate_name}/index.html");
-
-        let raw_html = self.http_repository.get(&url).await?;
-        let main_html = self.extract_main_content(&raw_html, "section#main-content")?;
-        let markdown = html2md::rewrite_html(&main_html, false);
-
-        Ok(markdown)
+        self.fetch_document_page(target, "/index.html").await
     }
 
     pub async fn fetch_document_page(
         &self,
-        crate_name: &str,
-        version: &str,
+        target: &crate::entity::docs::ResolvedVersion,
         path: &str,
     ) -> Result<String, crate::error::Error> {
-        let url = format!("https://docs.rs/{crate_name}/{version}/{crate_name}{path}");
+        let root = self.docs_root(target)?;
 
-        let raw_html = self.http_repository.get(&url).await?;
+        let raw_html = self.fetch_html(&root, path).await?;
         let main_html = self.extract_main_content(&raw_html, "section#main-content")?;
         let markdown = html2md::rewrite_html(&main_html, false);
 
-        Ok(markdown)
+        Ok(self.rewrite_links(&markdown, &root, path))
+    }
+
+    /// Returns the chunk of a markdown page starting at `cursor`, see [`crate::entity::docs::PageChunk`].
+    pub fn paginate(
+        &self,
+        markdown: &str,
+        cursor: Option<&str>,
+        max_length: usize,
+    ) -> Result<(String, crate::entity::docs::PageChunk), crate::error::Error> {
+        super::pagination::paginate(markdown, cursor, max_length)
+    }
+
+    /// Fetches an item page and splits it into declaration, docs, examples, methods and impls.
+    /// When `sections` is not empty, only those parts are returned.
+    pub async fn fetch_item_page(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        path: &str,
+        sections: &[crate::entity::docs::ItemPageSection],
+    ) -> Result<crate::entity::docs::ItemPage, crate::error::Error> {
+        use crate::entity::docs::ItemPageSection;
+
+        let root = self.docs_root(target)?;
+
+        let raw_html = self.fetch_html(&root, path).await?;
+        let mut page = super::item_page::parse_item_page(&raw_html)?;
+
+        page.docs = page.docs.map(|docs| self.rewrite_links(&docs, &root, path));
+        for member in page.methods.iter_mut().flatten() {
+            member.summary = member
+                .summary
+                .take()
+                .map(|summary| self.rewrite_links(&summary, &root, path));
+        }
+
+        if !sections.is_empty() {
+            let keep = |section: ItemPageSection| sections.contains(&section);
+
+            page.declaration = page
+                .declaration
+                .filter(|_| keep(ItemPageSection::Declaration));
+            page.docs = page.docs.filter(|_| keep(ItemPageSection::Docs));
+            page.examples = page.examples.filter(|_| keep(ItemPageSection::Examples));
+            page.methods = page.methods.filter(|_| keep(ItemPageSection::Methods));
+            page.trait_implementations = page
+                .trait_implementations
+                .filter(|_| keep(ItemPageSection::TraitImplementations));
+            page.auto_trait_implementations = page
+                .auto_trait_implementations
+                .filter(|_| keep(ItemPageSection::AutoTraitImplementations));
+            page.blanket_implementations = page
+                .blanket_implementations
+                .filter(|_| keep(ItemPageSection::BlanketImplementations));
+        }
+
+        Ok(page)
+    }
+
+    /// Fetches an item page and extracts a single method, associated const or type,
+    /// variant or field by its name or anchor.
+    pub async fn fetch_item_member(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        path: &str,
+        member: &str,
+    ) -> Result<crate::entity::docs::ItemMember, crate::error::Error> {
+        let root = self.docs_root(target)?;
+
+        let raw_html = self.fetch_html(&root, path).await?;
+        let mut member = super::item_page::parse_member(&raw_html, member)?;
+
+        member.summary = member
+            .summary
+            .map(|summary| self.rewrite_links(&summary, &root, path));
+        member.docs = member
+            .docs
+            .map(|docs| self.rewrite_links(&docs, &root, path));
+
+        Ok(member)
+    }
+
+    /// Resolves a fully-qualified Rust path such as `tokio::sync::mpsc::Sender` to its page.
+    ///
+    /// The path is looked up in the crate's items, falling back to the item the last segment
+    /// is a member of and then to the item it re-exports. Pages that are not listed at all,
+    /// such as modules and inlined items of other crates, are probed for.
+    pub async fn locate_item(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        rust_path: &str,
+        kind: Option<&str>,
+    ) -> Result<crate::entity::docs::ItemLocation, crate::error::Error> {
+        let hint = kind.map(super::item_path::kind_hint).transpose()?;
+        let segments = super::item_path::segments(&target.crate_name, rust_path);
+
+        let location = |path: String, kind: Option<String>, member: Option<String>| {
+            crate::entity::docs::ItemLocation {
+                rust_path: rust_path.to_owned(),
+                path,
+                kind,
+                member,
+            }
+        };
+        let item_kind = |item: &crate::entity::docs::Item| {
+            item.kind.clone().or_else(|| {
+                let href = item.href.as_deref()?;
+                let file_name = href.rsplit('/').next()?;
+                file_name.split_once('.').map(|(kind, _)| kind.to_owned())
+            })
+        };
+
+        if !segments.is_empty() && hint != Some(super::item_path::KindHint::Module) {
+            let prefixes = match hint {
+                Some(super::item_path::KindHint::Item(prefix)) => vec![prefix],
+                _ => Vec::new(),
+            };
+
+            match self.fetch_all_items(target).await {
+                Ok(items) => {
+                    if let Some(item) = super::item_path::find_exact(&items, &segments, &prefixes)
+                        && let Some(path) = super::item_path::page_path(item)
+                    {
+                        return Ok(location(path, item_kind(item), None));
+                    }
+
+                    // Members are tried before re-exports so that `Option::take` does not
+                    // resolve to an unrelated free function such as `mem::take`.
+                    if hint.is_none()
+                        && let Some((item, member)) =
+                            super::item_path::find_parent(&items, &segments)?
+                        && let Some(path) = super::item_path::page_path(item)
+                    {
+                        return Ok(location(path, item_kind(item), Some(member)));
+                    }
+
+                    if let Some(item) =
+                        super::item_path::find_reexport(&items, &segments, &prefixes)?
+                        && let Some(path) = super::item_path::page_path(item)
+                    {
+                        return Ok(location(path, item_kind(item), None));
+                    }
+                }
+                Err(e) => tracing::warn!("Probing pages for {rust_path}: {e}"),
+            }
+        }
+
+        let root = self.docs_root(target)?;
+
+        for (path, kind) in super::item_path::candidate_pages(&segments, hint) {
+            if self.fetch_html(&root, &path).await.is_ok() {
+                return Ok(location(path, Some(kind), None));
+            }
+        }
+
+        Err(crate::error::Error::ItemNotFound(rust_path.to_owned()))
     }
 
     pub(super) fn parse_all_items(
@@ -79,8 +548,8 @@ impl DocsUseCase {
             crate::error::Error::ScraperSelectorParse(e.to_string())
         })?;
 
-        let h3_elements = document.select(&h3_selector).into_iter();
-        let ul_elements = document.select(&ul_selector).into_iter();
+        let h3_elements = document.select(&h3_selector);
+        let ul_elements = document.select(&ul_selector);
 
         let zipped = h3_elements
             .zip(ul_elements)
@@ -88,12 +557,10 @@ impl DocsUseCase {
 
         let items = zipped
             .into_iter()
-            .map(|(h3, ul)| {
+            .flat_map(|(h3, ul)| {
                 let r#type = h3.inner_html().trim().to_string();
 
-                let items = ul
-                    .select(&a_selector)
-                    .into_iter()
+                ul.select(&a_selector)
                     .map(|a| {
                         let href = a.attr("href").map(|href| href.to_string());
                         let path = Some(a.inner_html());
@@ -101,130 +568,803 @@ impl DocsUseCase {
                             r#type: r#type.clone(),
                             href,
                             path,
+                            ..Default::default()
                         }
                     })
-                    .collect::<Vec<crate::entity::docs::Item>>();
-
-                items
+                    .collect::<Vec<crate::entity::docs::Item>>()
             })
-            .flatten()
             .collect::<Vec<crate::entity::docs::Item>>();
 
         Ok(items)
     }
 
+    /// Fetches the rustdoc JSON of a build: the `json.gz` docs.rs publishes, the
+    /// `{crate}.json` that `cargo rustdoc -- --output-format json` writes into `target/doc`,
+    /// or the `share/doc/rust/json` files of the `rust-docs-json` toolchain component.
+    pub(super) async fn fetch_rustdoc_json(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<serde_json::Value, crate::error::Error> {
+        use std::io::Read;
+
+        let crate_name = &target.crate_name;
+        let version = &target.resolved_version;
+
+        let json = match target.source {
+            crate::entity::docs::DocsSource::DocsRs => {
+                let url = format!("https://docs.rs/crate/{crate_name}/{version}/json.gz");
+
+                let compressed = self.http_repository.get_bytes(&url).await?;
+
+                let mut json = Vec::new();
+                flate2::read::GzDecoder::new(compressed.as_slice())
+                    .read_to_end(&mut json)
+                    .map_err(|e| {
+                        tracing::error!("{}", e);
+                        crate::error::Error::RustdocJson(e.to_string())
+                    })?;
+                json
+            }
+            crate::entity::docs::DocsSource::RustLang => {
+                return Err(crate::error::Error::RustdocJson(
+                    "doc.rust-lang.org does not publish rustdoc JSON".to_owned(),
+                ));
+            }
+            crate::entity::docs::DocsSource::Local => {
+                let path = self.local_crate_dir(crate_name)?.with_extension("json");
+                self.file_repository.read(&path).await?
+            }
+            crate::entity::docs::DocsSource::Toolchain => {
+                // Installed by the `rust-docs-json` component next to `html`.
+                let html_dir = self.toolchain_crate_dir(crate_name)?;
+                let path = html_dir
+                    .parent()
+                    .and_then(|dir| dir.parent())
+                    .unwrap_or(&html_dir)
+                    .join("json")
+                    .join(format!("{crate_name}.json"));
+                self.file_repository.read(&path).await?
+            }
+        };
+
+        serde_json::from_slice(&json).map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::RustdocJson(e.to_string())
+        })
+    }
+
+    /// Lists all items of a crate version. Items are read from rustdoc JSON when it is
+    /// available, and scraped from `all.html` otherwise (e.g. for builds predating rustdoc JSON).
     pub async fn fetch_all_items(
         &self,
-        crate_name: &str,
-        version: &str,
+        target: &crate::entity::docs::ResolvedVersion,
     ) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
-        let url = format!("https://docs.rs/{crate_name}/{version}/{crate_name}/all.html");
+        let crate_name = &target.crate_name;
+        let version = &target.resolved_version;
+
+        match self.fetch_rustdoc_json(target).await {
+            Ok(krate) => match super::rustdoc_json::parse_items(&krate) {
+                Ok(items) => return Ok(items),
+                Err(e) => {
+                    tracing::warn!("Falling back to all.html for {crate_name} {version}: {e}")
+                }
+            },
+            Err(e) => tracing::info!("Falling back to all.html for {crate_name} {version}: {e}"),
+        }
 
-        let raw_html = self.http_repository.get(&url).await?;
+        let root = self.docs_root(target)?;
+
+        let raw_html = self.fetch_html(&root, "/all.html").await?;
 
         let items = self.parse_all_items(&raw_html)?;
 
         Ok(items)
     }
 
-    pub async fn search_items(
+    /// Compares the public items of two versions of a crate: added, removed and
+    /// renamed-looking items, changed signatures and new deprecations. Signatures and
+    /// deprecations are only known for versions whose rustdoc JSON is available.
+    pub async fn diff_versions(
+        &self,
+        old: &crate::entity::docs::ResolvedVersion,
+        new: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<crate::entity::docs::ApiDiff, crate::error::Error> {
+        let (old_items, new_items) =
+            tokio::try_join!(self.fetch_all_items(old), self.fetch_all_items(new))?;
+
+        Ok(super::api_diff::diff_items(old_items, new_items))
+    }
+
+    /// Reads the release notes of the versions after `from` up to and including `to`.
+    /// The changelog is taken from the crate as packaged for `to`, or from its repository
+    /// when the package does not include one.
+    pub async fn fetch_changelog(
         &self,
         crate_name: &str,
-        version: &str,
-        keyword: &str,
-    ) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
-        let items = self.fetch_all_items(crate_name, version).await?;
+        from: &str,
+        to: &str,
+    ) -> Result<crate::entity::docs::Changelog, crate::error::Error> {
+        // `latest` is passed through unresolved when crates.io cannot be reached.
+        let parse = |version: &str| {
+            semver::Version::parse(version).map_err(|_| {
+                crate::error::Error::VersionResolution(format!(
+                    "Could not resolve {crate_name} `{version}` to a published version, which changelogs need. Is crates.io reachable?"
+                ))
+            })
+        };
+        let (from_version, to_version) = (parse(from)?, parse(to)?);
 
-        let mut schema_builder = tantivy::schema::Schema::builder();
-        schema_builder.add_text_field("type", tantivy::schema::STORED);
-        schema_builder.add_text_field("href", tantivy::schema::STORED);
-        schema_builder.add_text_field("path", tantivy::schema::TEXT | tantivy::schema::STORED);
-        let schema = schema_builder.build();
+        let (source, markdown) = self.fetch_changelog_file(crate_name, to).await?;
 
-        let index_path = tempfile::tempdir().map_err(|e| {
-            tracing::error!("{}", e);
-            crate::error::Error::CreateTempDir(e.to_string())
-        })?;
+        let sections = super::changelog::parse_sections(&markdown);
+        if sections.is_empty() {
+            return Err(crate::error::Error::Changelog(format!(
+                "No version headings found in {source}"
+            )));
+        }
 
-        let index = tantivy::Index::create_in_dir(&index_path, schema.clone())?;
-        let mut index_writer: tantivy::IndexWriter = index.writer(50_000_000)?;
+        Ok(crate::entity::docs::Changelog {
+            source,
+            sections: super::changelog::select_sections(sections, &from_version, &to_version),
+        })
+    }
+
+    /// Returns where the changelog was found and its content.
+    async fn fetch_changelog_file(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<(String, String), crate::error::Error> {
+        let url = format!("https://crates.io/api/v1/crates/{crate_name}/{version}/download");
 
-        let type_field = schema.get_field("type")?;
-        let href_field = schema.get_field("href")?;
-        let path_field = schema.get_field("path")?;
+        let packaged = match self.http_repository.get_bytes(&url).await {
+            Ok(archive) => super::changelog::find_in_crate(&archive),
+            Err(e) => Err(e),
+        };
 
-        for item in items {
-            let mut doc = tantivy::TantivyDocument::default();
-            doc.add_text(type_field, &item.r#type);
-            if let Some(href) = &item.href {
-                doc.add_text(href_field, href);
+        match packaged {
+            Ok(Some((path, content))) => {
+                return Ok((format!("{crate_name}-{version}.crate/{path}"), content));
             }
-            if let Some(path) = &item.path {
-                doc.add_text(path_field, path);
+            Ok(None) => tracing::info!("{crate_name} {version} does not package a changelog"),
+            Err(e) => tracing::warn!("Could not read the package of {crate_name} {version}: {e}"),
+        }
+
+        let repository = self
+            .crates_io_repository
+            .get_crate(crate_name)
+            .await?
+            .repository
+            .ok_or_else(|| {
+                crate::error::Error::Changelog(format!(
+                    "{crate_name} {version} packages no changelog and has no repository"
+                ))
+            })?;
+
+        for url in super::changelog::repository_urls(&repository, crate_name) {
+            match self.http_repository.get(&url).await {
+                Ok(content) => return Ok((url, content)),
+                Err(e) => tracing::debug!("No changelog at {url}: {e}"),
             }
-            index_writer.add_document(doc)?;
         }
 
-        index_writer.commit()?;
+        Err(crate::error::Error::Changelog(format!(
+            "No changelog in the package of {crate_name} {version} or in {repository}"
+        )))
+    }
 
-        let reader = index
-            .reader_builder()
-            .reload_policy(tantivy::ReloadPolicy::OnCommitWithDelay)
-            .try_into()?;
+    /// Reads the items of rustdoc's `search-index.js`, which unlike `all.html` also lists
+    /// methods, fields and variants, with their descriptions and function signatures.
+    /// Only builds of rustdoc 1.60 to 1.90 have a `search-index.js` that can be read.
+    pub(super) async fn fetch_search_index_items(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
+        let root = self.docs_root(target)?;
+        // rustdoc replaces dashes in crate names with underscores.
+        let crate_name = target.crate_name.replace('-', "_");
 
-        let query_parser = tantivy::query::QueryParser::for_index(&index, vec![path_field]);
+        let index_html = self.fetch_html(&root, "/index.html").await?;
+        let files = super::search_index_js::SearchIndexFiles::from_html(&index_html);
 
-        let query = query_parser.parse_query(keyword)?;
-        let searcher = reader.searcher();
+        let js = self.fetch_root_file(&root, &files.search_index()).await?;
+        let corpus = super::search_index_js::parse_crate_corpus(&js, &crate_name)?;
 
-        let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(10))?;
+        let mut descs = Vec::new();
+        for shard in 0..super::search_index_js::desc_shard_count(&corpus) {
+            let shard = match self
+                .fetch_root_file(&root, &files.desc_shard(&crate_name, shard))
+                .await
+            {
+                Ok(js) => super::search_index_js::parse_desc_shard(&js),
+                Err(e) => Err(e),
+            };
 
-        let mut result_items = Vec::new();
+            match shard {
+                Ok(shard) => descs.extend(shard),
+                Err(e) => {
+                    tracing::warn!("Skipping descriptions of {crate_name}: {e}");
+                    descs.clear();
+                    break;
+                }
+            }
+        }
 
-        for (_score, doc_address) in top_docs {
-            let retrieved_doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+        super::search_index_js::parse_items(&crate_name, &corpus, &descs)
+    }
 
-            let item_type = retrieved_doc
-                .get_first(type_field)
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string();
+    /// Items indexed for [`Self::search_items`]: the `search-index.js` items when the build
+    /// has one that can be read, and [`Self::fetch_all_items`] otherwise.
+    async fn fetch_searchable_items(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
+        let error = match self.fetch_search_index_items(target).await {
+            Ok(items) if !items.is_empty() => return Ok(items),
+            Ok(_) => "no items in the search index".to_owned(),
+            Err(e) => e.to_string(),
+        };
 
-            let href = retrieved_doc
-                .get_first(href_field)
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
+        tracing::warn!(
+            "Falling back to the item list for {} {}: {error}",
+            target.crate_name,
+            target.resolved_version
+        );
 
-            let path = retrieved_doc
-                .get_first(path_field)
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string());
+        self.fetch_all_items(target).await
+    }
 
-            let item = crate::entity::docs::Item {
-                r#type: item_type,
-                href,
-                path,
+    /// Ranked, typo-tolerant search over the items of a crate version.
+    pub async fn search_items(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::SearchHit>, crate::error::Error> {
+        let index = self
+            .search_index_cache
+            .get_or_build(&target.crate_name, &target.resolved_version, || {
+                self.fetch_searchable_items(target)
+            })
+            .await?;
+
+        index.search(keyword, limit)
+    }
+
+    /// Searches the items of several crate versions at once, e.g. to find which of a
+    /// project's dependencies defines `Bytes`.
+    ///
+    /// Scores of separate indexes are not comparable, so hits are ranked mostly by how
+    /// closely their name matches the keyword, then by their score relative to the best
+    /// hit of all crates, and finally by the crate order. Crates whose items cannot be
+    /// fetched are skipped, but a search that panics fails the whole call.
+    pub async fn search_crates(
+        &self,
+        targets: &[crate::entity::docs::ResolvedVersion],
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::CrateSearchHit>, crate::error::Error> {
+        let mut tasks = tokio::task::JoinSet::new();
+
+        for (position, target) in targets.iter().cloned().enumerate() {
+            let use_case = self.clone();
+            let keyword = keyword.to_owned();
+
+            tasks.spawn(async move {
+                let hits = use_case.search_items(&target, &keyword, limit).await;
+                (position, target, hits)
+            });
+        }
+
+        let mut results = Vec::new();
+        let mut last_error = None;
+
+        while let Some(task) = tasks.join_next().await {
+            match task {
+                Ok((position, target, Ok(hits))) => results.push((position, target, hits)),
+                Ok((_, target, Err(e))) => {
+                    tracing::warn!(
+                        "Skipping {} {}: {e}",
+                        target.crate_name,
+                        target.resolved_version
+                    );
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(crate::error::Error::SearchTask(e.to_string())),
+            }
+        }
+
+        if results.is_empty()
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+
+        results.sort_by_key(|(position, _, _)| *position);
+
+        let best_score = results
+            .iter()
+            .flat_map(|(_, _, hits)| hits)
+            .map(|hit| hit.score)
+            .fold(0.0, f32::max);
+
+        let mut hits = results
+            .into_iter()
+            .flat_map(|(_, target, hits)| {
+                hits.into_iter().map(move |hit| {
+                    let path = hit.item.path.as_deref().unwrap_or_default();
+                    let similarity = super::search_index::name_similarity(keyword, path);
+                    let relevance = if best_score > 0.0 {
+                        hit.score / best_score
+                    } else {
+                        0.0
+                    };
+
+                    crate::entity::docs::CrateSearchHit {
+                        crate_name: target.crate_name.clone(),
+                        version: target.resolved_version.clone(),
+                        hit: crate::entity::docs::SearchHit {
+                            score: 0.7 * similarity + 0.3 * relevance,
+                            item: hit.item,
+                        },
+                    }
+                })
+            })
+            .collect::<Vec<crate::entity::docs::CrateSearchHit>>();
+
+        // A stable sort keeps the crate order among equally ranked hits.
+        hits.sort_by(|a, b| b.hit.score.total_cmp(&a.hit.score));
+        hits.truncate(limit);
+
+        Ok(hits)
+    }
+
+    /// Fetches the crate root, module and item pages of a crate version and extracts
+    /// their prose, headings and code examples. Pages that fail to load are skipped.
+    async fn fetch_page_texts(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+    ) -> Result<Vec<crate::use_case::text_index::PageText>, crate::error::Error> {
+        let root = self.docs_root(target)?;
+        let items = self.fetch_all_items(target).await?;
+
+        let mut pages = vec![(target.crate_name.clone(), "/index.html".to_owned())];
+        for item in &items {
+            let (Some(path), Some(href)) = (&item.path, &item.href) else {
+                continue;
             };
+            let href = href.split('#').next().unwrap_or_default();
+
+            // Modules have no entry of their own in `all.html`.
+            let modules = path.split("::").collect::<Vec<&str>>();
+            for depth in 1..modules.len() {
+                let module = &modules[..depth];
+                pages.push((
+                    module.join("::"),
+                    format!("/{}/index.html", module.join("/")),
+                ));
+            }
 
-            result_items.push(item);
+            pages.push((path.clone(), format!("/{href}")));
         }
 
-        Ok(result_items)
+        let mut seen = std::collections::HashSet::new();
+        pages.retain(|(_, href)| seen.insert(href.clone()));
+        if pages.len() > Self::TEXT_INDEX_MAX_PAGES {
+            tracing::warn!(
+                "Indexing the first {} of {} pages of {}",
+                Self::TEXT_INDEX_MAX_PAGES,
+                pages.len(),
+                target.crate_name
+            );
+            pages.truncate(Self::TEXT_INDEX_MAX_PAGES);
+        }
+
+        let semaphore =
+            std::sync::Arc::new(tokio::sync::Semaphore::new(Self::TEXT_INDEX_CONCURRENCY));
+        let mut tasks = tokio::task::JoinSet::new();
+
+        for (path, href) in pages {
+            let use_case = self.clone();
+            let root = root.clone();
+            let semaphore = semaphore.clone();
+
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let html = use_case.fetch_html(&root, &href).await;
+                (path, href, html)
+            });
+        }
+
+        let mut texts = Vec::new();
+
+        while let Some(task) = tasks.join_next().await {
+            match task {
+                Ok((path, href, Ok(html))) => {
+                    texts.push(super::item_page::parse_page_text(&html, path, href)?)
+                }
+                Ok((_, href, Err(e))) => tracing::warn!("Skipping {href}: {e}"),
+                Err(e) => tracing::warn!("Page fetch task failed: {e}"),
+            }
+        }
+
+        texts.sort_by(|a, b| a.href.cmp(&b.href));
+
+        Ok(texts)
+    }
+
+    /// Full-text search over the prose, headings and code examples of every page of a
+    /// crate version, e.g. for concepts such as `backpressure` or `graceful shutdown`.
+    ///
+    /// The first search of a crate version crawls up to [`Self::TEXT_INDEX_MAX_PAGES`] of its
+    /// pages to build the index.
+    pub async fn search_text(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::TextSearchHit>, crate::error::Error> {
+        let index = self
+            .text_index_cache
+            .get_or_build(&target.crate_name, &target.resolved_version, || {
+                self.fetch_page_texts(target)
+            })
+            .await?;
+
+        index.search(query, limit)
+    }
+
+    /// Finds functions and methods by type signature, e.g. `NaiveDate -> DateTime<Utc>`,
+    /// ranked by how closely their inputs and output match the query.
+    pub async fn search_by_signature(
+        &self,
+        target: &crate::entity::docs::ResolvedVersion,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::SearchHit>, crate::error::Error> {
+        let query = super::signature::parse_query(query);
+        if query.is_empty() {
+            return Err(crate::error::Error::InvalidSignatureQuery(
+                "Expected types such as `A, B -> C`".to_owned(),
+            ));
+        }
+
+        let index = self
+            .search_index_cache
+            .get_or_build(&target.crate_name, &target.resolved_version, || {
+                self.fetch_searchable_items(target)
+            })
+            .await?;
+
+        let mut hits = index
+            .items()?
+            .into_iter()
+            .filter_map(|item| {
+                // `Self` of a method is the type its page documents.
+                let self_ty = match item.kind.as_deref() {
+                    Some("method") => item.path.as_deref()?.rsplit("::").nth(1),
+                    _ => None,
+                };
+                let function =
+                    super::signature::parse_function(item.signature.as_deref()?, self_ty)?;
+
+                let score = query.score(&function);
+                (score > 0.0).then_some(crate::entity::docs::SearchHit { score, item })
+            })
+            .collect::<Vec<crate::entity::docs::SearchHit>>();
+
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit);
+
+        Ok(hits)
     }
 }
 
 #[cfg(test)]
 mod test {
+    #[derive(Debug)]
+    struct FakeCratesIoRepository {
+        versions: Vec<(&'static str, bool)>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::repository::crates_io::CratesIoRepository for FakeCratesIoRepository {
+        async fn search_crate(
+            &self,
+            _query: &crate::record::crates_io::CrateSearchQuery,
+        ) -> Result<crate::record::crates_io::CrateSearchRecord, crate::error::Error> {
+            Ok(Default::default())
+        }
+
+        async fn list_versions(
+            &self,
+            _crate_name: &str,
+        ) -> Result<Vec<crate::record::crates_io::VersionRecord>, crate::error::Error> {
+            Ok(self
+                .versions
+                .iter()
+                .map(|(num, yanked)| crate::record::crates_io::VersionRecord {
+                    num: num.to_string(),
+                    yanked: *yanked,
+                    ..Default::default()
+                })
+                .collect())
+        }
+
+        async fn get_crate(
+            &self,
+            _crate_name: &str,
+        ) -> Result<crate::record::crates_io::CrateDetailRecord, crate::error::Error> {
+            Ok(Default::default())
+        }
+
+        async fn get_dependencies(
+            &self,
+            _crate_name: &str,
+            _version: &str,
+        ) -> Result<crate::record::crates_io::CrateDependenciesRecord, crate::error::Error>
+        {
+            Ok(Default::default())
+        }
+
+        async fn reverse_dependencies(
+            &self,
+            _crate_name: &str,
+            _page: u64,
+            _per_page: u64,
+        ) -> Result<crate::record::crates_io::ReverseDependenciesRecord, crate::error::Error>
+        {
+            Ok(Default::default())
+        }
+    }
+
+    fn use_case(
+        crates_io_repository: std::sync::Arc<
+            dyn crate::repository::crates_io::CratesIoRepository + Send + Sync,
+        >,
+    ) -> crate::use_case::docs::DocsUseCase {
+        let http_repository = std::sync::Arc::new(crate::repository::http::HttpRepositoryImpl {});
+
+        // Indexes only live in memory so that tests never touch the user's cache directory.
+        let search_index_config = crate::config::SearchIndexConfig {
+            persistent: false,
+            dir: std::path::PathBuf::new(),
+            capacity: 4,
+            ttl: std::time::Duration::from_secs(60),
+        };
+        let search_index_cache = std::sync::Arc::new(
+            crate::use_case::search_index::SearchIndexCache::new(&search_index_config),
+        );
+        let text_index_cache = std::sync::Arc::new(
+            crate::use_case::search_index::SearchIndexCache::new(&search_index_config),
+        );
+
+        crate::use_case::docs::DocsUseCase {
+            http_repository,
+            file_repository: std::sync::Arc::new(crate::repository::file::FileRepositoryImpl {}),
+            crates_io_repository,
+            search_index_cache,
+            text_index_cache,
+            version_cache: Default::default(),
+            local_docs: Default::default(),
+            std_docs: Default::default(),
+        }
+    }
 
     #[tokio::test]
     async fn test_fetch_document_page() -> Result<(), crate::error::Error> {
-        let http_repository = std::sync::Arc::new(crate::repository::http::HttpRepositoryImpl {});
-        let use_case = crate::use_case::docs::DocsUseCase { http_repository };
+        let use_case = use_case(std::sync::Arc::new(
+            crate::repository::crates_io::CratesIoRepositoryImpl {},
+        ));
 
-        let res = use_case.fetch_all_items("serde", "latest").await;
+        let target = use_case.resolve_version("serde", "latest", None).await?;
+        let res = use_case.fetch_all_items(&target).await;
 
         assert!(res.is_ok());
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_resolve_version() -> Result<(), crate::error::Error> {
+        let use_case = use_case(std::sync::Arc::new(FakeCratesIoRepository {
+            versions: vec![
+                ("1.1.0", false),
+                ("1.2.0", false),
+                ("1.2.1", true),
+                ("1.3.0-rc.1", false),
+                ("2.0.0", false),
+            ],
+        }));
+
+        let resolve = async |version: &str| {
+            use_case
+                .resolve_version("foo", version, None)
+                .await
+                .map(|resolved| resolved.resolved_version)
+        };
+
+        assert_eq!(resolve("latest").await?, "2.0.0");
+        assert_eq!(resolve("1").await?, "1.2.0");
+        assert_eq!(resolve("~1.1").await?, "1.1.0");
+        assert_eq!(resolve("1.2.1").await?, "1.2.1");
+        assert!(resolve("3").await.is_err());
+
+        Ok(())
+    }
+
+    /// Use case reading `my-crate` from `dir` as `cargo doc` output with a single `Widget` struct.
+    async fn local_crate(
+        dir: &std::path::Path,
+    ) -> Result<
+        (
+            crate::use_case::docs::DocsUseCase,
+            crate::entity::docs::ResolvedVersion,
+        ),
+        crate::error::Error,
+    > {
+        let crate_dir = dir.join("my_crate");
+        std::fs::create_dir_all(&crate_dir).unwrap();
+        std::fs::write(
+            crate_dir.join("index.html"),
+            r#"<section id="main-content"><h1>Private widgets</h1></section>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            crate_dir.join("all.html"),
+            r#"<section id="main-content"><h3>Structs</h3><ul><li><a href="struct.Widget.html">Widget</a></li></ul></section>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            crate_dir.join("struct.Widget.html"),
+            r#"<section id="main-content"><h1>Struct Widget</h1><details class="toggle top-doc"><div class="docblock"><p>A widget that applies backpressure to its producers.</p><pre class="rust"><code>let widget = Widget::new();</code></pre></div></details></section>"#,
+        )
+        .unwrap();
+
+        let mut use_case = use_case(std::sync::Arc::new(FakeCratesIoRepository {
+            versions: Vec::new(),
+        }));
+        use_case.local_docs = crate::config::LocalDocsConfig {
+            dir: Some(dir.to_owned()),
+            crates: vec!["my-crate".to_owned()],
+        };
+
+        let target = use_case.resolve_version("my-crate", "latest", None).await?;
+
+        Ok((use_case, target))
+    }
+
+    #[tokio::test]
+    async fn test_local_docs() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let (use_case, target) = local_crate(dir.path()).await?;
+
+        assert_eq!(target.source, crate::entity::docs::DocsSource::Local);
+        assert_eq!(target.resolved_version, "local");
+
+        let page = use_case.fetch_document_index_page(&target).await?;
+        assert!(page.contains("Private widgets"));
+
+        assert!(
+            use_case
+                .fetch_document_page(&target, "/../../etc/passwd")
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_items() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let (use_case, target) = local_crate(dir.path()).await?;
+
+        let hits = use_case.search_items(&target, "widgte", 10).await?;
+        assert_eq!(hits[0].item.href.as_deref(), Some("struct.Widget.html"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_crates() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let (use_case, target) = local_crate(dir.path()).await?;
+
+        let hits = use_case
+            .search_crates(std::slice::from_ref(&target), "widget", 10)
+            .await?;
+        assert_eq!(hits[0].crate_name, target.crate_name);
+        assert_eq!(hits[0].hit.item.href.as_deref(), Some("struct.Widget.html"));
+        assert_eq!(hits[0].hit.score, 1.0);
+
+        let hits = use_case
+            .search_crates(std::slice::from_ref(&target), "widget", 0)
+            .await?;
+        assert!(hits.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_text() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let (use_case, target) = local_crate(dir.path()).await?;
+
+        let hits = use_case.search_text(&target, "backpressure", 10).await?;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].path, "Widget");
+        assert_eq!(hits[0].href, "/struct.Widget.html");
+        assert_eq!(
+            hits[0].snippet.as_deref(),
+            Some("A widget that applies **backpressure** to its producers")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_changelog_unresolved_version() {
+        let use_case = use_case(std::sync::Arc::new(FakeCratesIoRepository {
+            versions: Vec::new(),
+        }));
+
+        let result = use_case.fetch_changelog("foo", "1.0.0", "latest").await;
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::VersionResolution(message)) if message.contains("`latest`")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_std_version() -> Result<(), crate::error::Error> {
+        let use_case = use_case(std::sync::Arc::new(FakeCratesIoRepository {
+            versions: Vec::new(),
+        }));
+
+        let target = use_case.resolve_version("std", "latest", None).await?;
+        assert_eq!(target.source, crate::entity::docs::DocsSource::RustLang);
+        assert_eq!(target.resolved_version, "stable");
+
+        let resolve = async |version: &str| {
+            use_case
+                .resolve_version("core", version, None)
+                .await
+                .map(|resolved| resolved.resolved_version)
+        };
+
+        assert_eq!(resolve("nightly").await?, "nightly");
+        assert_eq!(resolve("1.80").await?, "1.80.0");
+        assert!(resolve("^1.80").await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_toolchain_docs() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let html_dir = dir.path().join("share/doc/rust/html");
+        std::fs::create_dir_all(html_dir.join("std")).unwrap();
+        std::fs::write(
+            html_dir.join("std/index.html"),
+            r#"<section id="main-content"><h1>The Rust Standard Library</h1></section>"#,
+        )
+        .unwrap();
+
+        let mut use_case = use_case(std::sync::Arc::new(FakeCratesIoRepository {
+            versions: Vec::new(),
+        }));
+        use_case.std_docs = crate::config::StdDocsConfig {
+            toolchain_dir: Some(html_dir),
+            prefer_toolchain: true,
+        };
+
+        let target = use_case.resolve_version("std", "stable", None).await?;
+        assert_eq!(target.source, crate::entity::docs::DocsSource::Toolchain);
+
+        let page = use_case.fetch_document_index_page(&target).await?;
+        assert!(page.contains("The Rust Standard Library"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,421 @@
+// Parses rustdoc item pages (`struct.Vec.html`, `trait.Iterator.html`, ...) into their parts.
+// The markup differs slightly between rustdoc releases, so parts that cannot be found
+// are left empty instead of failing the whole page.
+
+fn selector(css: &str) -> Result<scraper::Selector, crate::error::Error> {
+    scraper::Selector::parse(css).map_err(|e| {
+        tracing::error!("{}", e);
+        crate::error::Error::ScraperSelectorParse(e.to_string())
+    })
+}
+
+fn has_class(element: scraper::ElementRef<'_>, class: &str) -> bool {
+    element.value().classes().any(|c| c == class)
+}
+
+fn markdown(element: scraper::ElementRef<'_>) -> String {
+    html2md::rewrite_html(&element.inner_html(), false)
+        .trim()
+        .to_owned()
+}
+
+/// Text of a code header or declaration, keeping the line break before `where` clauses
+/// and leaving out the labels of collapsed toggles such as "Show 76 methods".
+pub(super) fn code_text(element: scraper::ElementRef<'_>) -> String {
+    let mut text = String::new();
+
+    for node in element.descendants() {
+        let in_toggle_label = node
+            .ancestors()
+            .take_while(|ancestor| ancestor.id() != element.id())
+            .any(|ancestor| {
+                ancestor
+                    .value()
+                    .as_element()
+                    .is_some_and(|e| e.name() == "summary")
+            });
+        if in_toggle_label {
+            continue;
+        }
+
+        match node.value() {
+            scraper::Node::Text(t) => text.push_str(t),
+            scraper::Node::Element(e) if e.classes().any(|c| c == "where") => text.push('\n'),
+            _ => {}
+        }
+    }
+
+    text.trim().to_owned()
+}
+
+/// Documentation block of a member, which rustdoc places next to the `<summary>`
+/// that holds the member's `<section>`.
+pub(super) fn member_docblock(section: scraper::ElementRef<'_>) -> Option<scraper::ElementRef<'_>> {
+    let summary = section
+        .parent()
+        .and_then(scraper::ElementRef::wrap)
+        .filter(|summary| summary.value().name() == "summary")?;
+    let details = summary.parent().and_then(scraper::ElementRef::wrap)?;
+
+    details
+        .children()
+        .filter_map(scraper::ElementRef::wrap)
+        .find(|child| has_class(*child, "docblock"))
+}
+
+fn member(
+    section: scraper::ElementRef<'_>,
+    code_header: &scraper::Selector,
+    paragraph: &scraper::Selector,
+) -> Option<crate::entity::docs::ItemMember> {
+    let anchor = section.value().id()?.to_owned();
+    let (kind, name) = anchor.split_once('.')?;
+    if !matches!(kind, "method" | "tymethod") {
+        return None;
+    }
+    let name = name.to_owned();
+    let signature = section.select(code_header).next().map(code_text)?;
+
+    let summary = member_docblock(section)
+        .and_then(|docblock| docblock.select(paragraph).next())
+        .map(markdown);
+
+    Some(crate::entity::docs::ItemMember {
+        name,
+        anchor,
+        signature,
+        summary,
+        ..Default::default()
+    })
+}
+
+/// Anchor prefixes of the members of an item page, in lookup order.
+const MEMBER_ANCHOR_PREFIXES: [&str; 6] = [
+    "method",
+    "tymethod",
+    "associatedconstant",
+    "associatedtype",
+    "variant",
+    "structfield",
+];
+
+/// Finds a single member by name (`retain`) or anchor (`method.retain`) and returns its
+/// signature and full documentation.
+pub(super) fn parse_member(
+    html: &str,
+    member: &str,
+) -> Result<crate::entity::docs::ItemMember, crate::error::Error> {
+    let document = scraper::Html::parse_document(html);
+
+    let id_selector = selector("section#main-content [id]")?;
+    let signature_selector = selector(".code-header, code")?;
+    let paragraph_selector = selector("p")?;
+
+    let member = member.trim().trim_start_matches('#');
+    let anchors = if MEMBER_ANCHOR_PREFIXES
+        .iter()
+        .any(|prefix| member.starts_with(&format!("{prefix}.")))
+    {
+        vec![member.to_owned()]
+    } else {
+        MEMBER_ANCHOR_PREFIXES
+            .iter()
+            .map(|prefix| format!("{prefix}.{member}"))
+            .collect()
+    };
+
+    let element = anchors
+        .iter()
+        .find_map(|anchor| {
+            document
+                .select(&id_selector)
+                .find(|element| element.value().id() == Some(anchor.as_str()))
+        })
+        .ok_or_else(|| {
+            crate::error::Error::HtmlMainContentNotFound(format!(
+                "Member not found on the page: {member}"
+            ))
+        })?;
+
+    let anchor = element.value().id().unwrap_or_default().to_owned();
+    let name = anchor
+        .split_once('.')
+        .map(|(_, name)| name)
+        .unwrap_or(&anchor)
+        .to_owned();
+    let signature = element
+        .select(&signature_selector)
+        .next()
+        .map(code_text)
+        .unwrap_or_default();
+
+    // Variants and fields are followed by their docs, other members are wrapped in a toggle.
+    let docblock = member_docblock(element).or_else(|| {
+        element
+            .next_siblings()
+            .find_map(scraper::ElementRef::wrap)
+            .filter(|sibling| has_class(*sibling, "docblock"))
+    });
+
+    Ok(crate::entity::docs::ItemMember {
+        name,
+        anchor,
+        signature,
+        summary: docblock
+            .and_then(|docblock| docblock.select(&paragraph_selector).next())
+            .map(markdown),
+        docs: docblock.map(markdown),
+    })
+}
+
+/// Extracts the prose, headings and code examples of all documentation blocks of a page
+/// (the item's own docs, and those of its methods and fields) for full-text indexing.
+pub(super) fn parse_page_text(
+    html: &str,
+    path: String,
+    href: String,
+) -> Result<super::text_index::PageText, crate::error::Error> {
+    let document = scraper::Html::parse_document(html);
+    let docblock_selector = selector(".docblock")?;
+    let heading_selector = selector("h1, h2, h3, h4, h5, h6")?;
+    let pre_selector = selector("pre")?;
+
+    let mut page = super::text_index::PageText {
+        path,
+        href,
+        ..Default::default()
+    };
+
+    let mut paragraphs = Vec::new();
+
+    for docblock in document.select(&docblock_selector) {
+        page.headings.extend(
+            docblock
+                .select(&heading_selector)
+                .map(|heading| heading.text().collect::<String>().trim().to_owned()),
+        );
+        page.examples.extend(
+            docblock
+                .select(&pre_selector)
+                .map(|pre| pre.text().collect::<String>().trim().to_owned()),
+        );
+
+        // Prose without the code blocks, one paragraph per block element.
+        let mut paragraph = String::new();
+        for node in docblock.descendants() {
+            let in_code_block = node.ancestors().any(|ancestor| {
+                ancestor
+                    .value()
+                    .as_element()
+                    .is_some_and(|e| e.name() == "pre")
+            });
+            if in_code_block {
+                continue;
+            }
+
+            match node.value() {
+                scraper::Node::Text(t) => paragraph.push_str(t),
+                scraper::Node::Element(e)
+                    if matches!(
+                        e.name(),
+                        "p" | "li"
+                            | "pre"
+                            | "table"
+                            | "blockquote"
+                            | "h1"
+                            | "h2"
+                            | "h3"
+                            | "h4"
+                            | "h5"
+                            | "h6"
+                    ) =>
+                {
+                    paragraphs.push(std::mem::take(&mut paragraph));
+                }
+                _ => {}
+            }
+        }
+        paragraphs.push(paragraph);
+    }
+
+    page.docs = paragraphs
+        .iter()
+        .map(|paragraph| {
+            paragraph
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n");
+    page.headings.retain(|heading| !heading.is_empty());
+    page.examples.retain(|example| !example.is_empty());
+
+    Ok(page)
+}
+
+pub(super) fn parse_item_page(
+    html: &str,
+) -> Result<crate::entity::docs::ItemPage, crate::error::Error> {
+    let document = scraper::Html::parse_document(html);
+
+    let title_selector = selector("section#main-content h1")?;
+    let declaration_selector = selector("pre.item-decl, div.item-decl pre")?;
+    let top_doc_selector = selector(
+        "section#main-content details.top-doc > div.docblock, section#main-content > div.docblock",
+    )?;
+    let example_selector = selector("div.example-wrap > pre.rust")?;
+    let method_selector =
+        selector("#implementations-list section.method, div.methods section.method")?;
+    let code_header_selector = selector(".code-header")?;
+    let paragraph_selector = selector("p")?;
+
+    let impl_headers = |list_id: &str| -> Result<Vec<String>, crate::error::Error> {
+        let selector = selector(&format!("#{list_id} section.impl > h3.code-header"))?;
+        Ok(document.select(&selector).map(code_text).collect())
+    };
+
+    let title = document
+        .select(&title_selector)
+        .next()
+        .map(|h1| {
+            h1.text()
+                .filter(|text| text.trim() != "Copy item path")
+                .collect::<String>()
+        })
+        .map(|title| title.split_whitespace().collect::<Vec<&str>>().join(" "));
+
+    let declaration = document.select(&declaration_selector).next().map(code_text);
+
+    let top_doc = document.select(&top_doc_selector).next();
+    let docs = top_doc.map(markdown);
+    let examples = top_doc.map(|docblock| {
+        docblock
+            .select(&example_selector)
+            .map(|pre| pre.text().collect::<String>().trim().to_owned())
+            .collect()
+    });
+
+    let methods = document
+        .select(&method_selector)
+        .filter_map(|section| member(section, &code_header_selector, &paragraph_selector))
+        .collect();
+
+    Ok(crate::entity::docs::ItemPage {
+        title,
+        declaration,
+        docs,
+        examples,
+        methods: Some(methods),
+        trait_implementations: Some(impl_headers("trait-implementations-list")?),
+        auto_trait_implementations: Some(impl_headers("synthetic-implementations-list")?),
+        blanket_implementations: Some(impl_headers("blanket-implementations-list")?),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    const HTML: &str = r##"<section id="main-content" class="content">
+<div class="main-heading"><h1>Struct <span class="struct">Vec</span>&nbsp;<button id="copy-path">Copy item path</button></h1></div>
+<pre class="rust item-decl"><code>pub struct Vec&lt;T&gt; {<details class="toggle type-contents-toggle"><summary class="hideme"><span>Show 1 field</span></summary> <span class="comment">/* private fields */</span> </details>}</code></pre>
+<details class="toggle top-doc" open><summary class="hideme"><span>Expand description</span></summary><div class="docblock">
+<p>A contiguous growable array type.</p>
+<div class="example-wrap"><pre class="rust rust-example-rendered"><code>let v = Vec::new();</code></pre></div>
+</div></details>
+<h2 id="implementations">Implementations</h2><div id="implementations-list">
+<details class="toggle implementors-toggle" open><summary><section id="impl-Vec%3CT%3E" class="impl"><h3 class="code-header">impl&lt;T&gt; Vec&lt;T&gt;</h3></section></summary>
+<div class="impl-items">
+<details class="toggle method-toggle" open><summary><section id="method.retain" class="method"><h4 class="code-header">pub fn <a href="#method.retain" class="fn">retain</a>&lt;F&gt;(&amp;mut self, f: F)<div class="where">where
+    F: FnMut(&amp;T) -&gt; bool,</div></h4></section></summary><div class="docblock"><p>Retains only the elements specified by the predicate.</p><p>More.</p></div></details>
+<section id="method.len" class="method"><h4 class="code-header">pub fn <a href="#method.len" class="fn">len</a>(&amp;self) -&gt; usize</h4></section>
+</div></details></div>
+<h2 id="fields">Fields</h2><span id="structfield.len" class="structfield section-header"><a href="#structfield.len" class="anchor field">§</a><code>len: usize</code></span><div class="docblock"><p>Number of elements.</p></div>
+<h2 id="trait-implementations">Trait Implementations</h2><div id="trait-implementations-list">
+<details class="toggle implementors-toggle"><summary><section id="impl-Clone-for-Vec%3CT%3E" class="impl"><h3 class="code-header">impl&lt;T: Clone&gt; Clone for Vec&lt;T&gt;</h3></section></summary>
+<div class="impl-items"><section id="method.clone" class="method trait-impl"><h4 class="code-header">fn clone(&amp;self) -&gt; Self</h4></section></div></details></div>
+<h2 id="synthetic-implementations">Auto Trait Implementations</h2><div id="synthetic-implementations-list">
+<section id="impl-Send-for-Vec%3CT%3E" class="impl"><h3 class="code-header">impl&lt;T&gt; Send for Vec&lt;T&gt;<div class="where">where
+    T: Send,</div></h3></section></div>
+<h2 id="blanket-implementations">Blanket Implementations</h2><div id="blanket-implementations-list">
+<details class="toggle implementors-toggle"><summary><section id="impl-Any-for-T" class="impl"><h3 class="code-header">impl&lt;T&gt; Any for T</h3></section></summary></details></div>
+</section>"##;
+
+    #[test]
+    fn test_parse_item_page() -> Result<(), crate::error::Error> {
+        let page = super::parse_item_page(HTML)?;
+
+        assert_eq!(page.title.as_deref(), Some("Struct Vec"));
+        assert_eq!(
+            page.declaration.as_deref(),
+            Some("pub struct Vec<T> { /* private fields */ }")
+        );
+        assert!(
+            page.docs
+                .as_deref()
+                .is_some_and(|docs| docs.starts_with("A contiguous growable array type."))
+        );
+        assert_eq!(page.examples, Some(vec!["let v = Vec::new();".to_owned()]));
+
+        let methods = page.methods.unwrap_or_default();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].anchor, "method.retain");
+        assert_eq!(
+            methods[0].signature,
+            "pub fn retain<F>(&mut self, f: F)\nwhere\n    F: FnMut(&T) -> bool,"
+        );
+        assert_eq!(
+            methods[0].summary.as_deref(),
+            Some("Retains only the elements specified by the predicate.")
+        );
+        assert_eq!(methods[1].name, "len");
+        assert_eq!(methods[1].summary, None);
+
+        assert_eq!(
+            page.trait_implementations,
+            Some(vec!["impl<T: Clone> Clone for Vec<T>".to_owned()])
+        );
+        assert_eq!(
+            page.auto_trait_implementations,
+            Some(vec![
+                "impl<T> Send for Vec<T>\nwhere\n    T: Send,".to_owned()
+            ])
+        );
+        assert_eq!(
+            page.blanket_implementations,
+            Some(vec!["impl<T> Any for T".to_owned()])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_member() -> Result<(), crate::error::Error> {
+        let retain = super::parse_member(HTML, "retain")?;
+        assert_eq!(retain.anchor, "method.retain");
+        let docs = retain.docs.unwrap_or_default();
+        assert!(docs.starts_with("Retains only the elements specified by the predicate."));
+        assert!(docs.ends_with("More."));
+
+        let len = super::parse_member(HTML, "structfield.len")?;
+        assert_eq!(len.signature, "len: usize");
+        assert_eq!(len.docs.as_deref(), Some("Number of elements."));
+
+        assert!(super::parse_member(HTML, "missing").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_page_text() -> Result<(), crate::error::Error> {
+        let html = r#"<div class="docblock"><h2>Examples</h2><p>Splits the <code>hr</code> <span>work</span>.</p><hr><p>After</p><pre class="rust"><code>let x = 1;</code></pre></div>"#;
+
+        let page =
+            super::parse_page_text(html, "Widget".to_owned(), "/struct.Widget.html".to_owned())?;
+        assert_eq!(page.headings, vec!["Examples"]);
+        assert_eq!(page.examples, vec!["let x = 1;"]);
+        assert_eq!(page.docs, "Examples\nSplits the hr work.\nAfter");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+// Resolves fully-qualified Rust paths such as `tokio::sync::mpsc::Sender` to documentation
+// pages. rustdoc names pages `{module}/{kind}.{name}.html`, lists items under the module that
+// defines them, and inlines items re-exported from other crates, so a path is looked up in
+// the item list first and its page is guessed from the file name conventions last.
+
+/// Item kinds a page can be probed for, in the order they are tried.
+const PROBE_PREFIXES: [&str; 12] = [
+    "struct.",
+    "enum.",
+    "trait.",
+    "fn.",
+    "macro.",
+    "type.",
+    "constant.",
+    "static.",
+    "union.",
+    "derive.",
+    "attr.",
+    "primitive.",
+];
+
+/// Item kinds that have members (methods, variants, fields, ...).
+const PARENT_PREFIXES: [&str; 6] = [
+    "struct.",
+    "enum.",
+    "trait.",
+    "union.",
+    "type.",
+    "primitive.",
+];
+
+/// Kind hint accepted from clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum KindHint {
+    Module,
+    Item(&'static str),
+}
+
+pub(super) fn kind_hint(kind: &str) -> Result<KindHint, crate::error::Error> {
+    let kind = match kind.trim().to_lowercase().as_str() {
+        "mod" | "module" => return Ok(KindHint::Module),
+        "fn" | "function" | "method" => "function",
+        "type" | "type_alias" | "typedef" => "type_alias",
+        "const" | "constant" => "constant",
+        "derive" | "proc_derive" => "proc_derive",
+        "attr" | "attribute" | "proc_attribute" => "proc_attribute",
+        other => return kind_prefix(other).map(KindHint::Item),
+    };
+
+    kind_prefix(kind).map(KindHint::Item)
+}
+
+fn kind_prefix(kind: &str) -> Result<&'static str, crate::error::Error> {
+    super::rustdoc_json::item_kind_info(kind)
+        .map(|(_, prefix)| prefix)
+        .ok_or_else(|| crate::error::Error::ItemNotFound(format!("Unknown item kind: {kind}")))
+}
+
+/// Path segments below the crate root, without generic arguments:
+/// `tokio::sync::mpsc::Sender<T>` becomes `["sync", "mpsc", "Sender"]`.
+pub(super) fn segments(crate_name: &str, rust_path: &str) -> Vec<String> {
+    let rust_path = rust_path.split(['<', '(']).next().unwrap_or_default();
+    let crate_dir = crate_name.replace('-', "_");
+
+    let mut segments = rust_path
+        .split("::")
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_owned)
+        .collect::<Vec<String>>();
+
+    if segments
+        .first()
+        .is_some_and(|first| *first == crate_dir || first == "crate")
+    {
+        segments.remove(0);
+    }
+
+    segments
+}
+
+fn file_name(item: &crate::entity::docs::Item) -> &str {
+    item.href
+        .as_deref()
+        .and_then(|href| href.rsplit('/').next())
+        .unwrap_or_default()
+}
+
+fn matching_items<'a>(
+    items: &'a [crate::entity::docs::Item],
+    prefixes: &[&str],
+) -> impl Iterator<Item = (&'a crate::entity::docs::Item, &'a str)> {
+    items
+        .iter()
+        .filter(|item| item.href.is_some())
+        .filter(move |item| {
+            prefixes.is_empty()
+                || prefixes
+                    .iter()
+                    .any(|prefix| file_name(item).starts_with(prefix))
+        })
+        .filter_map(|item| item.path.as_deref().map(|path| (item, path)))
+}
+
+/// Finds the item listed exactly at `segments`.
+pub(super) fn find_exact<'a>(
+    items: &'a [crate::entity::docs::Item],
+    segments: &[String],
+    prefixes: &[&str],
+) -> Option<&'a crate::entity::docs::Item> {
+    let full_path = segments.join("::");
+
+    matching_items(items, prefixes)
+        .find(|(_, path)| *path == full_path)
+        .map(|(item, _)| item)
+}
+
+/// Finds the item `segments` may be a re-export of: an item of the same name whose module
+/// contains, or is contained in, the requested module, such as `runtime::builder::Builder`
+/// for `runtime::Builder`. The candidate closest to the crate root wins, and several equally
+/// close candidates are reported as ambiguous rather than guessed.
+pub(super) fn find_reexport<'a>(
+    items: &'a [crate::entity::docs::Item],
+    segments: &[String],
+    prefixes: &[&str],
+) -> Result<Option<&'a crate::entity::docs::Item>, crate::error::Error> {
+    let Some((name, modules)) = segments.split_last() else {
+        return Ok(None);
+    };
+
+    let mut candidates = matching_items(items, prefixes)
+        .filter_map(|(item, path)| {
+            let item_segments = path.split("::").collect::<Vec<&str>>();
+            let (item_name, item_modules) = item_segments.split_last()?;
+
+            let common = modules
+                .iter()
+                .zip(item_modules)
+                .take_while(|(module, item_module)| module == *item_module)
+                .count();
+
+            (item_name == name && common == modules.len().min(item_modules.len())).then_some((
+                item_modules.len(),
+                item,
+                path,
+            ))
+        })
+        .collect::<Vec<(usize, &crate::entity::docs::Item, &str)>>();
+
+    candidates.sort_by_key(|(depth, _, path)| (*depth, *path));
+
+    match candidates.as_slice() {
+        [] => Ok(None),
+        [(_, item, _)] => Ok(Some(item)),
+        [(depth, item, _), (next_depth, _, _), ..] if depth < next_depth => Ok(Some(item)),
+        [(depth, _, _), ..] => {
+            let paths = candidates
+                .iter()
+                .filter(|(candidate_depth, _, _)| candidate_depth == depth)
+                .map(|(_, _, path)| *path)
+                .collect::<Vec<&str>>();
+
+            Err(crate::error::Error::AmbiguousItemPath(format!(
+                "`{}` could be any of {}",
+                segments.join("::"),
+                paths.join(", ")
+            )))
+        }
+    }
+}
+
+/// Finds the item at `segments`, or else the item it is a re-export of.
+fn find_item<'a>(
+    items: &'a [crate::entity::docs::Item],
+    segments: &[String],
+    prefixes: &[&str],
+) -> Result<Option<&'a crate::entity::docs::Item>, crate::error::Error> {
+    match find_exact(items, segments, prefixes) {
+        Some(item) => Ok(Some(item)),
+        None => find_reexport(items, segments, prefixes),
+    }
+}
+
+pub(super) fn page_path(item: &crate::entity::docs::Item) -> Option<String> {
+    item.href
+        .as_deref()
+        .map(|href| format!("/{}", href.trim_start_matches("./").trim_start_matches('/')))
+}
+
+/// Finds the item `segments` is a member of, e.g. `Vec` for `["vec", "Vec", "retain"]`.
+pub(super) fn find_parent<'a>(
+    items: &'a [crate::entity::docs::Item],
+    segments: &[String],
+) -> Result<Option<(&'a crate::entity::docs::Item, String)>, crate::error::Error> {
+    let Some((member, parent)) = segments.split_last() else {
+        return Ok(None);
+    };
+    if parent.is_empty() {
+        return Ok(None);
+    }
+
+    let parent = find_item(items, parent, &PARENT_PREFIXES)?;
+
+    Ok(parent.map(|item| (item, member.to_owned())))
+}
+
+/// Page paths `segments` may live at, following rustdoc's file name conventions.
+pub(super) fn candidate_pages(
+    segments: &[String],
+    hint: Option<KindHint>,
+) -> Vec<(String, String)> {
+    let Some((name, modules)) = segments.split_last() else {
+        return vec![("/index.html".to_owned(), "module".to_owned())];
+    };
+    let modules = modules
+        .iter()
+        .map(|module| format!("/{module}"))
+        .collect::<String>();
+
+    let module_page = (format!("{modules}/{name}/index.html"), "module".to_owned());
+    let item_page = |prefix: &str| {
+        (
+            format!("{modules}/{prefix}{name}.html"),
+            prefix.trim_end_matches('.').to_owned(),
+        )
+    };
+
+    match hint {
+        Some(KindHint::Module) => vec![module_page],
+        Some(KindHint::Item(prefix)) => vec![item_page(prefix)],
+        None => std::iter::once(module_page)
+            .chain(PROBE_PREFIXES.iter().map(|prefix| item_page(prefix)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn item(path: &str, href: &str) -> crate::entity::docs::Item {
+        crate::entity::docs::Item {
+            r#type: "Structs".to_owned(),
+            href: Some(href.to_owned()),
+            path: Some(path.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_find_item() -> Result<(), crate::error::Error> {
+        let items = vec![
+            item("sync::mpsc::Sender", "sync/mpsc/struct.Sender.html"),
+            item(
+                "sync::broadcast::Sender",
+                "sync/broadcast/struct.Sender.html",
+            ),
+            item(
+                "runtime::builder::Builder",
+                "runtime/builder/struct.Builder.html",
+            ),
+            item("spawn", "fn.spawn.html"),
+            item("option::Option", "option/enum.Option.html"),
+            item("mem::take", "mem/fn.take.html"),
+            item("iter::Iterator", "iter/trait.Iterator.html"),
+            item("iter::zip", "iter/fn.zip.html"),
+        ];
+
+        let find = |rust_path: &str, prefixes: &[&str]| {
+            let segments = super::segments("tokio", rust_path);
+            super::find_item(&items, &segments, prefixes)
+                .map(|item| item.and_then(super::page_path))
+        };
+
+        assert_eq!(
+            find("tokio::sync::mpsc::Sender<T>", &[])?.as_deref(),
+            Some("/sync/mpsc/struct.Sender.html")
+        );
+        // `tokio::runtime::Builder` is a re-export of `tokio::runtime::builder::Builder`.
+        assert_eq!(
+            find("tokio::runtime::Builder", &[])?.as_deref(),
+            Some("/runtime/builder/struct.Builder.html")
+        );
+
+        let super::KindHint::Item(fn_prefix) = super::kind_hint("fn")? else {
+            panic!("`fn` is not a module");
+        };
+        assert_eq!(
+            find("crate::spawn", &[fn_prefix])?.as_deref(),
+            Some("/fn.spawn.html")
+        );
+        assert_eq!(find("tokio::spawn", &["struct."])?, None);
+
+        // Two items of the same name are ambiguous rather than picked alphabetically.
+        assert!(matches!(
+            find("tokio::sync::Sender", &[]),
+            Err(crate::error::Error::AmbiguousItemPath(_))
+        ));
+
+        // A member whose name collides with a free function in another module.
+        assert_eq!(find("tokio::option::Option::take", &[])?, None);
+
+        let segments = super::segments("std", "std::option::Option::take");
+        let (parent, member) = super::find_parent(&items, &segments)?.unwrap();
+        assert_eq!(parent.path.as_deref(), Some("option::Option"));
+        assert_eq!(member, "take");
+
+        let segments = super::segments("std", "Iterator::zip");
+        let (parent, member) = super::find_parent(&items, &segments)?.unwrap();
+        assert_eq!(parent.path.as_deref(), Some("iter::Iterator"));
+        assert_eq!(member, "zip");
+
+        let segments = super::segments("tokio", "tokio::sync::mpsc::Sender::send");
+        let (parent, member) = super::find_parent(&items, &segments)?.unwrap();
+        assert_eq!(parent.path.as_deref(), Some("sync::mpsc::Sender"));
+        assert_eq!(member, "send");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_candidate_pages() {
+        let segments = super::segments("serde", "serde::de::Deserialize");
+        let pages = super::candidate_pages(&segments, None);
+
+        assert_eq!(pages[0].0, "/de/Deserialize/index.html");
+        assert!(pages.contains(&("/de/trait.Deserialize.html".to_owned(), "trait".to_owned())));
+    }
+}
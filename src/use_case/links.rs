@@ -0,0 +1,268 @@
+// Rewrites the links of converted markdown so that an agent can follow them with the docs tools.
+// rustdoc emits relative hrefs such as `../de/trait.Deserialize.html`; they are resolved against
+// the page URL and annotated with the `crate`, `version` and `path` the tools expect.
+
+/// A documentation page a link points to, in terms of the docs tools parameters.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct LinkTarget {
+    pub crate_name: String,
+    pub version: String,
+    pub path: String,
+    pub source: crate::entity::docs::DocsSource,
+}
+
+/// Roots of the local documentation directories, used to recognize `file://` links.
+#[derive(Debug, Default)]
+pub(super) struct LocalRoots<'a> {
+    pub local: Option<&'a std::path::Path>,
+    pub toolchain: Option<&'a std::path::Path>,
+}
+
+fn join_path(segments: &[&str], fragment: Option<&str>) -> String {
+    let mut path = format!("/{}", segments.join("/"));
+    if let Some(fragment) = fragment {
+        path.push('#');
+        path.push_str(fragment);
+    }
+    path
+}
+
+/// Maps an absolute documentation URL to the page it designates.
+pub(super) fn locate(url: &reqwest::Url, roots: &LocalRoots<'_>) -> Option<LinkTarget> {
+    let segments = url.path_segments()?.collect::<Vec<&str>>();
+
+    match (url.scheme(), url.host_str()) {
+        (_, Some("docs.rs")) => {
+            let (crate_name, version, rest) = match segments.as_slice() {
+                [crate_name, version, rest @ ..] if *crate_name != "crate" => {
+                    (*crate_name, *version, rest)
+                }
+                _ => return None,
+            };
+
+            // docs.rs serves non-default targets under an extra `{target}` segment.
+            let crate_dir = crate_name.replace('-', "_");
+            let path = match rest {
+                [dir, path @ ..] if *dir == crate_dir => path,
+                [_target, dir, path @ ..] if *dir == crate_dir => path,
+                _ => return None,
+            };
+
+            Some(LinkTarget {
+                crate_name: crate_name.to_owned(),
+                version: version.to_owned(),
+                path: join_path(path, url.fragment()),
+                source: crate::entity::docs::DocsSource::DocsRs,
+            })
+        }
+        (_, Some("doc.rust-lang.org")) => match segments.as_slice() {
+            [channel, crate_name, path @ ..]
+                if super::docs::DocsUseCase::STD_CRATES.contains(crate_name) =>
+            {
+                Some(LinkTarget {
+                    crate_name: (*crate_name).to_owned(),
+                    version: (*channel).to_owned(),
+                    path: join_path(path, url.fragment()),
+                    source: crate::entity::docs::DocsSource::RustLang,
+                })
+            }
+            _ => None,
+        },
+        ("file", _) => {
+            let file = url.to_file_path().ok()?;
+
+            let (relative, version, source) = [
+                (roots.local, "local", crate::entity::docs::DocsSource::Local),
+                (
+                    roots.toolchain,
+                    "toolchain",
+                    crate::entity::docs::DocsSource::Toolchain,
+                ),
+            ]
+            .into_iter()
+            .find_map(|(root, version, source)| {
+                let relative = file.strip_prefix(root?).ok()?;
+                Some((relative.to_owned(), version, source))
+            })?;
+
+            let segments = relative
+                .iter()
+                .map(|segment| segment.to_str())
+                .collect::<Option<Vec<&str>>>()?;
+            let (crate_name, path) = segments.split_first()?;
+
+            // Besides crates, rustdoc output holds `src/` and files such as `static.files`.
+            let is_crate = match source {
+                crate::entity::docs::DocsSource::Toolchain => {
+                    super::docs::DocsUseCase::STD_CRATES.contains(crate_name)
+                }
+                _ => *crate_name != "src" && !crate_name.contains('.'),
+            };
+            if !is_crate {
+                return None;
+            }
+
+            Some(LinkTarget {
+                crate_name: (*crate_name).to_owned(),
+                version: version.to_owned(),
+                path: join_path(path, url.fragment()),
+                source,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Length of the link destination starting at `text`, which follows a `](`.
+fn destination_len(text: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            c if c.is_whitespace() => return None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn rewrite_destination(
+    destination: &str,
+    page_url: &reqwest::Url,
+    roots: &LocalRoots<'_>,
+) -> Option<String> {
+    // Links within the page (`#examples`) have nothing to follow.
+    if destination.is_empty() || destination.starts_with('#') {
+        return None;
+    }
+
+    let url = page_url.join(destination).ok()?;
+
+    let annotated = match locate(&url, roots) {
+        Some(target) => {
+            let source = match target.source {
+                crate::entity::docs::DocsSource::DocsRs => String::new(),
+                _ => format!(
+                    " source={}",
+                    serde_json::to_value(target.source)
+                        .ok()?
+                        .as_str()
+                        .unwrap_or_default()
+                ),
+            };
+
+            format!(
+                "{url} \"crate={} version={} path={}{source}\"",
+                target.crate_name, target.version, target.path
+            )
+        }
+        None => url.to_string(),
+    };
+
+    Some(annotated)
+}
+
+/// Makes every link of `markdown` absolute and annotates links to documentation pages,
+/// e.g. `[Deserialize](https://docs.rs/serde/1.0.228/serde/de/trait.Deserialize.html "crate=serde version=1.0.228 path=/de/trait.Deserialize.html")`.
+/// Fenced code blocks are left untouched.
+pub(super) fn rewrite_links(
+    markdown: &str,
+    page_url: &reqwest::Url,
+    roots: &LocalRoots<'_>,
+) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    let mut in_fence = false;
+
+    for line in markdown.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if in_fence {
+            output.push_str(line);
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("](") {
+            let (before, after) = rest.split_at(start + 2);
+            output.push_str(before);
+
+            let Some(len) = destination_len(after) else {
+                rest = after;
+                continue;
+            };
+
+            let (destination, after) = after.split_at(len);
+            match rewrite_destination(destination, page_url, roots) {
+                Some(rewritten) => output.push_str(&rewritten),
+                None => output.push_str(destination),
+            }
+            rest = after;
+        }
+        output.push_str(rest);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_rewrite_links() {
+        let page_url =
+            reqwest::Url::parse("https://docs.rs/serde/1.0.228/serde/de/index.html").unwrap();
+        let roots = super::LocalRoots::default();
+
+        let rewrite = |markdown: &str| super::rewrite_links(markdown, &page_url, &roots);
+
+        assert_eq!(
+            rewrite("See [Serialize](../ser/trait.Serialize.html#tymethod.serialize)."),
+            "See [Serialize](https://docs.rs/serde/1.0.228/serde/ser/trait.Serialize.html#tymethod.serialize \"crate=serde version=1.0.228 path=/ser/trait.Serialize.html#tymethod.serialize\")."
+        );
+        assert_eq!(
+            rewrite("[Option](https://doc.rust-lang.org/nightly/core/option/enum.Option.html)"),
+            "[Option](https://doc.rust-lang.org/nightly/core/option/enum.Option.html \"crate=core version=nightly path=/option/enum.Option.html source=rust_lang\")"
+        );
+        assert_eq!(
+            rewrite(
+                "[Value](https://docs.rs/serde_json/1.0.0/x86_64-pc-windows-msvc/serde_json/enum.Value.html)"
+            ),
+            "[Value](https://docs.rs/serde_json/1.0.0/x86_64-pc-windows-msvc/serde_json/enum.Value.html \"crate=serde_json version=1.0.0 path=/enum.Value.html\")"
+        );
+        assert_eq!(
+            rewrite("## [§](#examples)Examples"),
+            "## [§](#examples)Examples"
+        );
+        assert_eq!(
+            rewrite("```\nlet f = [g][0](1);\n```\n"),
+            "```\nlet f = [g][0](1);\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_locate_local() {
+        let roots = super::LocalRoots {
+            local: Some(std::path::Path::new("/work/target/doc")),
+            toolchain: None,
+        };
+        let url =
+            reqwest::Url::parse("file:///work/target/doc/my_crate/struct.Widget.html").unwrap();
+
+        assert_eq!(
+            super::locate(&url, &roots),
+            Some(super::LinkTarget {
+                crate_name: "my_crate".to_owned(),
+                version: "local".to_owned(),
+                path: "/struct.Widget.html".to_owned(),
+                source: crate::entity::docs::DocsSource::Local,
+            })
+        );
+
+        let url = reqwest::Url::parse("file:///work/target/doc/src/my_crate/lib.rs.html").unwrap();
+        assert_eq!(super::locate(&url, &roots), None);
+    }
+}
@@ -1,2 +1,14 @@
+mod api_diff;
+mod changelog;
 pub mod crates_io;
 pub mod docs;
+mod item_page;
+mod item_path;
+mod links;
+mod pagination;
+mod rustdoc_json;
+pub mod search_index;
+mod search_index_js;
+mod signature;
+pub mod text_index;
+pub mod workspace;
@@ -0,0 +1,116 @@
+// Splits long markdown pages into chunks that fit a client's context budget.
+// Chunks end right before a heading where possible, so that sections stay together,
+// and the split points only depend on the page content, so cursors are stable.
+
+/// Byte offsets of the lines starting a markdown heading, ignoring fenced code blocks.
+fn heading_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && trimmed.starts_with('#') {
+            offsets.push(offset);
+        }
+
+        offset += line.len();
+    }
+
+    offsets
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Returns the chunk of `text` starting at `cursor` that is at most `max_length` bytes long.
+///
+/// The chunk ends before the last heading that fits, otherwise at the last blank line,
+/// otherwise at the last line break, and only as a last resort in the middle of a line.
+pub(super) fn paginate(
+    text: &str,
+    cursor: Option<&str>,
+    max_length: usize,
+) -> Result<(String, crate::entity::docs::PageChunk), crate::error::Error> {
+    let offset = match cursor {
+        Some(cursor) => cursor
+            .parse::<usize>()
+            .ok()
+            .filter(|offset| *offset <= text.len() && text.is_char_boundary(*offset))
+            .ok_or_else(|| crate::error::Error::InvalidCursor(cursor.to_owned()))?,
+        None => 0,
+    };
+    let max_length = max_length.max(1);
+
+    let end = if text.len() - offset <= max_length {
+        text.len()
+    } else {
+        let limit = floor_char_boundary(text, offset + max_length);
+        // Always make progress, even when `max_length` is shorter than a single character.
+        let limit = if limit > offset {
+            limit
+        } else {
+            offset + text[offset..].chars().next().map_or(0, char::len_utf8)
+        };
+        let window = &text[offset..limit];
+
+        let at_heading = heading_offsets(text)
+            .into_iter()
+            .rfind(|heading| *heading > offset && *heading <= limit);
+        let at_blank_line = window.rfind("\n\n").map(|i| offset + i + 2);
+        let at_line_break = window.rfind('\n').map(|i| offset + i + 1);
+
+        at_heading
+            .or(at_blank_line)
+            .or(at_line_break)
+            .filter(|end| *end > offset)
+            .unwrap_or(limit)
+    };
+
+    let chunk = crate::entity::docs::PageChunk {
+        offset,
+        length: end - offset,
+        total_length: text.len(),
+        next_cursor: (end < text.len()).then(|| end.to_string()),
+    };
+
+    Ok((text[offset..end].to_owned(), chunk))
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_paginate() -> Result<(), crate::error::Error> {
+        let text = "# Title\n\nIntro.\n\n## First\n\nAlpha.\n\n```sh\n# not a heading\n```\n\n## Second\n\nBeta.\n";
+
+        let (chunk, page) = super::paginate(text, None, 40)?;
+        assert_eq!(chunk, "# Title\n\nIntro.\n\n");
+        assert_eq!(page.next_cursor.as_deref(), Some("17"));
+
+        // The fenced `# not a heading` line is not a split point.
+        let (chunk, page) = super::paginate(text, page.next_cursor.as_deref(), 50)?;
+        assert_eq!(
+            chunk,
+            "## First\n\nAlpha.\n\n```sh\n# not a heading\n```\n\n"
+        );
+
+        let (chunk, page) = super::paginate(text, page.next_cursor.as_deref(), 50)?;
+        assert_eq!(chunk, "## Second\n\nBeta.\n");
+        assert_eq!(page.next_cursor, None);
+
+        // A chunk is only cut mid-line when nothing better fits.
+        let (chunk, _) = super::paginate("abcdef", None, 4)?;
+        assert_eq!(chunk, "abcd");
+
+        assert!(super::paginate(text, Some("1000"), 10).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,810 @@
+// The rustdoc JSON format is still unstable and its field names change between
+// format versions, so it is read through `serde_json::Value` and unknown shapes
+// degrade to missing fields instead of hard errors.
+type Json = serde_json::Value;
+
+/// Returns the `all.html` section heading and the file name prefix for a rustdoc item kind.
+pub(super) fn item_kind_info(kind: &str) -> Option<(&'static str, &'static str)> {
+    let info = match kind {
+        "struct" => ("Structs", "struct."),
+        "enum" => ("Enums", "enum."),
+        "union" => ("Unions", "union."),
+        "trait" => ("Traits", "trait."),
+        "trait_alias" => ("Trait Aliases", "traitalias."),
+        "function" => ("Functions", "fn."),
+        "type_alias" | "typedef" => ("Type Aliases", "type."),
+        "constant" => ("Constants", "constant."),
+        "static" => ("Statics", "static."),
+        "macro" => ("Macros", "macro."),
+        "proc_attribute" => ("Attribute Macros", "attr."),
+        "proc_derive" => ("Derive Macros", "derive."),
+        "primitive" => ("Primitive Types", "primitive."),
+        "extern_type" => ("Foreign Types", "foreigntype."),
+        _ => return None,
+    };
+
+    Some(info)
+}
+
+/// Lists every public item of the documented crate, like `all.html` does,
+/// enriched with signatures, doc summaries, deprecation and stability.
+pub(super) fn parse_items(
+    krate: &Json,
+) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
+    let index = krate
+        .get("index")
+        .and_then(Json::as_object)
+        .ok_or_else(|| crate::error::Error::RustdocJson("Missing `index` table".to_owned()))?;
+    let paths = krate
+        .get("paths")
+        .and_then(Json::as_object)
+        .ok_or_else(|| crate::error::Error::RustdocJson("Missing `paths` table".to_owned()))?;
+
+    let mut items = paths
+        .iter()
+        .filter(|(_, summary)| summary.get("crate_id").and_then(Json::as_u64) == Some(0))
+        .filter_map(|(id, summary)| {
+            let kind = summary.get("kind")?.as_str()?;
+            let (heading, prefix) = item_kind_info(kind)?;
+
+            let segments = summary
+                .get("path")?
+                .as_array()?
+                .iter()
+                .filter_map(Json::as_str)
+                .collect::<Vec<&str>>();
+
+            let (name, modules) = segments.split_last()?;
+            let modules = modules.get(1..).unwrap_or_default();
+
+            let href = modules
+                .iter()
+                .map(|module| format!("{module}/"))
+                .chain(std::iter::once(format!("{prefix}{name}.html")))
+                .collect::<String>();
+
+            let path = modules
+                .iter()
+                .copied()
+                .chain(std::iter::once(*name))
+                .collect::<Vec<&str>>()
+                .join("::");
+
+            let item = index.get(id);
+
+            Some(crate::entity::docs::Item {
+                r#type: heading.to_owned(),
+                href: Some(href),
+                path: Some(path),
+                kind: Some(kind.to_owned()),
+                full_path: Some(segments.join("::")),
+                signature: item.and_then(|item| render_signature(name, item)),
+                summary: item.and_then(doc_summary),
+                deprecation: item.and_then(deprecation),
+                stability: item.and_then(stability),
+            })
+        })
+        .collect::<Vec<crate::entity::docs::Item>>();
+
+    items.sort_by(|a, b| (&a.r#type, &a.path).cmp(&(&b.r#type, &b.path)));
+
+    Ok(items)
+}
+
+fn doc_summary(item: &Json) -> Option<String> {
+    let docs = item.get("docs")?.as_str()?.trim();
+
+    let summary = docs
+        .split("\n\n")
+        .next()?
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    (!summary.is_empty()).then_some(summary)
+}
+
+fn deprecation(item: &Json) -> Option<crate::entity::docs::Deprecation> {
+    let deprecation = item.get("deprecation")?.as_object()?;
+
+    let field = |key: &str| {
+        deprecation
+            .get(key)
+            .and_then(Json::as_str)
+            .map(str::to_owned)
+    };
+
+    Some(crate::entity::docs::Deprecation {
+        since: field("since"),
+        note: field("note"),
+    })
+}
+
+fn stability(item: &Json) -> Option<String> {
+    let attrs = item.get("attrs")?.as_array()?;
+
+    // Attributes are strings in older format versions and `{"other": "#[...]"}` in newer ones.
+    let levels = attrs
+        .iter()
+        .filter_map(|attr| {
+            attr.as_str()
+                .or_else(|| attr.get("other").and_then(Json::as_str))
+        })
+        .filter_map(stability_level)
+        .collect::<Vec<&str>>();
+
+    if levels.contains(&"unstable") {
+        Some("unstable".to_owned())
+    } else if levels.contains(&"stable") {
+        Some("stable".to_owned())
+    } else {
+        None
+    }
+}
+
+/// Reads the level of a `#[stable(..)]` or `#[unstable(..)]` attribute, which newer rustc
+/// versions print as `#[attr = Stability { level: Unstable { .. }, .. }]`. Other attributes,
+/// such as `#[rustc_const_unstable(..)]` on stable functions, are ignored.
+fn stability_level(attr: &str) -> Option<&'static str> {
+    let attr = attr
+        .trim()
+        .strip_prefix("#[")
+        .or_else(|| attr.trim().strip_prefix("#!["))?
+        .trim_start();
+
+    let name = attr
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or_default();
+
+    match name {
+        "stable" => Some("stable"),
+        "unstable" => Some("unstable"),
+        "attr" => {
+            let stability = attr
+                .strip_prefix("attr")?
+                .trim_start()
+                .strip_prefix('=')?
+                .trim_start()
+                .strip_prefix("Stability")?;
+            let level = stability.split("level:").nth(1)?.trim_start();
+
+            if level.starts_with("Unstable") {
+                Some("unstable")
+            } else if level.starts_with("Stable") {
+                Some("stable")
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Splits rustdoc's externally tagged enums (`{"function": {...}}`) into tag and content.
+fn variant(value: &Json) -> Option<(&str, &Json)> {
+    match value {
+        Json::Object(map) if map.len() == 1 => map.iter().next().map(|(k, v)| (k.as_str(), v)),
+        Json::String(tag) => Some((tag.as_str(), &Json::Null)),
+        _ => None,
+    }
+}
+
+/// Reads a boolean that was renamed between format versions (e.g. `mutable` -> `is_mutable`).
+fn flag(value: &Json, keys: &[&str]) -> bool {
+    keys.iter()
+        .find_map(|key| value.get(*key).and_then(Json::as_bool))
+        .unwrap_or(false)
+}
+
+fn when(enabled: bool, text: &str) -> &str {
+    if enabled { text } else { "" }
+}
+
+/// Reads a field that was renamed between format versions (e.g. `decl` -> `sig`).
+fn field<'a>(value: &'a Json, keys: &[&str]) -> Option<&'a Json> {
+    keys.iter()
+        .find_map(|key| value.get(*key).filter(|v| !v.is_null()))
+}
+
+pub(super) fn render_signature(name: &str, item: &Json) -> Option<String> {
+    let (kind, inner) = variant(item.get("inner")?)?;
+
+    let signature = match kind {
+        "function" => render_function(name, inner),
+        "struct" | "enum" | "union" => format!(
+            "pub {kind} {name}{}{}",
+            render_generic_params(inner.get("generics")),
+            render_where(inner.get("generics"))
+        ),
+        "trait" => {
+            let bounds = render_bounds(inner.get("bounds"));
+            format!(
+                "pub {}{}trait {name}{}{}{}",
+                when(flag(inner, &["is_unsafe"]), "unsafe "),
+                when(flag(inner, &["is_auto"]), "auto "),
+                render_generic_params(inner.get("generics")),
+                if bounds.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {bounds}")
+                },
+                render_where(inner.get("generics"))
+            )
+        }
+        "trait_alias" => format!(
+            "pub trait {name}{} = {}",
+            render_generic_params(inner.get("generics")),
+            render_bounds(field(inner, &["params", "bounds"]))
+        ),
+        "type_alias" | "typedef" => format!(
+            "pub type {name}{} = {}",
+            render_generic_params(inner.get("generics")),
+            render_type(field(inner, &["type"])?)
+        ),
+        "constant" => format!(
+            "pub const {name}: {}",
+            render_type(field(inner, &["type"])?)
+        ),
+        "static" => format!(
+            "pub static {}{name}: {}",
+            when(flag(inner, &["is_mutable", "mutable"]), "mut "),
+            render_type(field(inner, &["type"])?)
+        ),
+        "macro" => inner.as_str()?.lines().next()?.trim().to_owned(),
+        "proc_macro" => match inner.get("kind").and_then(Json::as_str) {
+            Some("derive") => format!("#[derive({name})]"),
+            Some("attr") => format!("#[{name}]"),
+            _ => format!("{name}!()"),
+        },
+        _ => return None,
+    };
+
+    Some(signature)
+}
+
+fn render_function(name: &str, function: &Json) -> String {
+    let header = function.get("header").unwrap_or(&Json::Null);
+    let sig = field(function, &["sig", "decl"]).unwrap_or(&Json::Null);
+
+    let mut qualifiers = String::new();
+    if flag(header, &["is_const", "const"]) {
+        qualifiers.push_str("const ");
+    }
+    if flag(header, &["is_async", "async"]) {
+        qualifiers.push_str("async ");
+    }
+    if flag(header, &["is_unsafe", "unsafe"]) {
+        qualifiers.push_str("unsafe ");
+    }
+    if let Some(abi) = header
+        .get("abi")
+        .and_then(|abi| variant(abi).map(|(abi, _)| abi))
+        && abi != "Rust"
+    {
+        qualifiers.push_str(&format!("extern \"{abi}\" "));
+    }
+
+    let inputs = sig
+        .get("inputs")
+        .and_then(Json::as_array)
+        .map(|inputs| {
+            inputs
+                .iter()
+                .filter_map(|input| {
+                    let input = input.as_array()?;
+                    Some(render_input(input.first()?.as_str()?, input.get(1)?))
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+
+    let output = field(sig, &["output"])
+        .map(|output| format!(" -> {}", render_type(output)))
+        .unwrap_or_default();
+
+    format!(
+        "pub {qualifiers}fn {name}{}({inputs}){output}{}",
+        render_generic_params(function.get("generics")),
+        render_where(function.get("generics"))
+    )
+}
+
+fn render_input(name: &str, ty: &Json) -> String {
+    if name != "self" {
+        return format!("{name}: {}", render_type(ty));
+    }
+
+    match variant(ty) {
+        Some(("generic", Json::String(generic))) if generic == "Self" => "self".to_owned(),
+        Some(("borrowed_ref", reference))
+            if reference.get("type").and_then(|t| t.get("generic"))
+                == Some(&Json::from("Self")) =>
+        {
+            let lifetime = reference
+                .get("lifetime")
+                .and_then(Json::as_str)
+                .map(|lifetime| format!("{lifetime} "))
+                .unwrap_or_default();
+            let mutability = when(flag(reference, &["is_mutable", "mutable"]), "mut ");
+            format!("&{lifetime}{mutability}self")
+        }
+        _ => format!("self: {}", render_type(ty)),
+    }
+}
+
+fn render_generic_params(generics: Option<&Json>) -> String {
+    let Some(params) = generics
+        .and_then(|generics| generics.get("params"))
+        .and_then(Json::as_array)
+    else {
+        return String::new();
+    };
+
+    let params = params
+        .iter()
+        .filter_map(|param| {
+            let name = param.get("name")?.as_str()?;
+            let (kind, inner) = variant(param.get("kind")?)?;
+
+            match kind {
+                "lifetime" => {
+                    let outlives = inner
+                        .get("outlives")
+                        .and_then(Json::as_array)
+                        .map(|outlives| {
+                            outlives
+                                .iter()
+                                .filter_map(Json::as_str)
+                                .collect::<Vec<&str>>()
+                                .join(" + ")
+                        })
+                        .unwrap_or_default();
+                    Some(if outlives.is_empty() {
+                        name.to_owned()
+                    } else {
+                        format!("{name}: {outlives}")
+                    })
+                }
+                "type" => {
+                    // `impl Trait` arguments show up as synthetic type parameters.
+                    if flag(inner, &["is_synthetic", "synthetic"]) {
+                        return None;
+                    }
+                    let bounds = render_bounds(inner.get("bounds"));
+                    Some(if bounds.is_empty() {
+                        name.to_owned()
+                    } else {
+                        format!("{name}: {bounds}")
+                    })
+                }
+                "const" => Some(format!("const {name}: {}", render_type(inner.get("type")?))),
+                _ => None,
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    }
+}
+
+fn render_where(generics: Option<&Json>) -> String {
+    let Some(predicates) = generics
+        .and_then(|generics| generics.get("where_predicates"))
+        .and_then(Json::as_array)
+    else {
+        return String::new();
+    };
+
+    let predicates = predicates
+        .iter()
+        .filter_map(|predicate| {
+            let (kind, inner) = variant(predicate)?;
+            match kind {
+                "bound_predicate" => Some(format!(
+                    "{}: {}",
+                    render_type(inner.get("type")?),
+                    render_bounds(inner.get("bounds"))
+                )),
+                "eq_predicate" => Some(format!(
+                    "{} = {}",
+                    render_type(inner.get("lhs")?),
+                    inner
+                        .get("rhs")
+                        .and_then(|rhs| rhs.get("type"))
+                        .map(render_type)
+                        .unwrap_or_default()
+                )),
+                _ => None,
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if predicates.is_empty() {
+        String::new()
+    } else {
+        format!(" where {}", predicates.join(", "))
+    }
+}
+
+fn render_bounds(bounds: Option<&Json>) -> String {
+    let Some(bounds) = bounds.and_then(Json::as_array) else {
+        return String::new();
+    };
+
+    bounds
+        .iter()
+        .filter_map(|bound| {
+            let (kind, inner) = variant(bound)?;
+            match kind {
+                "trait_bound" => {
+                    let modifier = match inner.get("modifier").and_then(Json::as_str) {
+                        Some("maybe") => "?",
+                        Some("maybe_const") => "~const ",
+                        _ => "",
+                    };
+                    Some(format!(
+                        "{}{modifier}{}",
+                        render_higher_ranked(inner.get("generic_params")),
+                        render_path(inner.get("trait")?)
+                    ))
+                }
+                "outlives" => inner.as_str().map(str::to_owned),
+                "use" => Some(format!(
+                    "use<{}>",
+                    inner
+                        .as_array()?
+                        .iter()
+                        .filter_map(|arg| arg.as_str().or_else(|| variant(arg)?.1.as_str()))
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )),
+                _ => None,
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" + ")
+}
+
+fn render_higher_ranked(params: Option<&Json>) -> String {
+    let names = params
+        .and_then(Json::as_array)
+        .map(|params| {
+            params
+                .iter()
+                .filter_map(|param| param.get("name").and_then(Json::as_str))
+                .collect::<Vec<&str>>()
+        })
+        .unwrap_or_default();
+
+    if names.is_empty() {
+        String::new()
+    } else {
+        format!("for<{}> ", names.join(", "))
+    }
+}
+
+fn render_path(path: &Json) -> String {
+    let name = field(path, &["path", "name"])
+        .and_then(Json::as_str)
+        .unwrap_or("_");
+
+    format!("{name}{}", render_generic_args(path.get("args")))
+}
+
+fn render_generic_args(args: Option<&Json>) -> String {
+    let Some((kind, inner)) = args.and_then(variant) else {
+        return String::new();
+    };
+
+    match kind {
+        "angle_bracketed" => {
+            let args = inner
+                .get("args")
+                .and_then(Json::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|arg| {
+                    let (kind, inner) = variant(arg)?;
+                    Some(match kind {
+                        "lifetime" => inner.as_str()?.to_owned(),
+                        "type" => render_type(inner),
+                        "const" => inner
+                            .get("expr")
+                            .and_then(Json::as_str)
+                            .unwrap_or("_")
+                            .to_owned(),
+                        _ => "_".to_owned(),
+                    })
+                });
+
+            let constraints = field(inner, &["constraints", "bindings"])
+                .and_then(Json::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|constraint| {
+                    let name = constraint.get("name")?.as_str()?;
+                    let args = render_generic_args(constraint.get("args"));
+                    let (kind, binding) = variant(constraint.get("binding")?)?;
+                    Some(match kind {
+                        "equality" => {
+                            let term = match variant(binding) {
+                                Some(("type", ty)) => render_type(ty),
+                                Some(("constant", constant)) => constant
+                                    .get("expr")
+                                    .and_then(Json::as_str)
+                                    .unwrap_or("_")
+                                    .to_owned(),
+                                _ => render_type(binding),
+                            };
+                            format!("{name}{args} = {term}")
+                        }
+                        _ => format!("{name}{args}: {}", render_bounds(Some(binding))),
+                    })
+                });
+
+            let all = args.chain(constraints).collect::<Vec<String>>();
+
+            if all.is_empty() {
+                String::new()
+            } else {
+                format!("<{}>", all.join(", "))
+            }
+        }
+        "parenthesized" => {
+            let inputs = inner
+                .get("inputs")
+                .and_then(Json::as_array)
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .map(render_type)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            let output = field(inner, &["output"])
+                .map(|output| format!(" -> {}", render_type(output)))
+                .unwrap_or_default();
+            format!("({inputs}){output}")
+        }
+        _ => "(..)".to_owned(),
+    }
+}
+
+pub(super) fn render_type(ty: &Json) -> String {
+    let Some((kind, inner)) = variant(ty) else {
+        return "_".to_owned();
+    };
+
+    match kind {
+        "resolved_path" => render_path(inner),
+        "generic" | "primitive" => inner.as_str().unwrap_or("_").to_owned(),
+        "dyn_trait" => {
+            let traits = inner
+                .get("traits")
+                .and_then(Json::as_array)
+                .map(|traits| {
+                    traits
+                        .iter()
+                        .filter_map(|poly| {
+                            Some(format!(
+                                "{}{}",
+                                render_higher_ranked(poly.get("generic_params")),
+                                render_path(poly.get("trait")?)
+                            ))
+                        })
+                        .collect::<Vec<String>>()
+                })
+                .unwrap_or_default();
+            let lifetime = inner
+                .get("lifetime")
+                .and_then(Json::as_str)
+                .into_iter()
+                .map(str::to_owned);
+            format!(
+                "dyn {}",
+                traits
+                    .into_iter()
+                    .chain(lifetime)
+                    .collect::<Vec<String>>()
+                    .join(" + ")
+            )
+        }
+        "function_pointer" => {
+            let sig = field(inner, &["sig", "decl"]).unwrap_or(&Json::Null);
+            let inputs = sig
+                .get("inputs")
+                .and_then(Json::as_array)
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .filter_map(|input| input.as_array()?.get(1).map(render_type))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            let output = field(sig, &["output"])
+                .map(|output| format!(" -> {}", render_type(output)))
+                .unwrap_or_default();
+            format!(
+                "{}fn({inputs}){output}",
+                render_higher_ranked(inner.get("generic_params"))
+            )
+        }
+        "tuple" => {
+            let elements = inner
+                .as_array()
+                .map(|elements| elements.iter().map(render_type).collect::<Vec<String>>())
+                .unwrap_or_default();
+            match elements.as_slice() {
+                [single] => format!("({single},)"),
+                _ => format!("({})", elements.join(", ")),
+            }
+        }
+        "slice" => format!("[{}]", render_type(inner)),
+        "array" => format!(
+            "[{}; {}]",
+            inner.get("type").map(render_type).unwrap_or_default(),
+            inner.get("len").and_then(Json::as_str).unwrap_or("_")
+        ),
+        "pat" => inner
+            .get("type")
+            .map(render_type)
+            .unwrap_or_else(|| "_".to_owned()),
+        "impl_trait" => format!("impl {}", render_bounds(Some(inner))),
+        "raw_pointer" => format!(
+            "*{} {}",
+            if flag(inner, &["is_mutable", "mutable"]) {
+                "mut"
+            } else {
+                "const"
+            },
+            inner.get("type").map(render_type).unwrap_or_default()
+        ),
+        "borrowed_ref" => format!(
+            "&{}{}{}",
+            inner
+                .get("lifetime")
+                .and_then(Json::as_str)
+                .map(|lifetime| format!("{lifetime} "))
+                .unwrap_or_default(),
+            when(flag(inner, &["is_mutable", "mutable"]), "mut "),
+            inner.get("type").map(render_type).unwrap_or_default()
+        ),
+        "qualified_path" => {
+            let name = inner.get("name").and_then(Json::as_str).unwrap_or("_");
+            let self_type = inner.get("self_type").map(render_type).unwrap_or_default();
+            match field(inner, &["trait"]) {
+                Some(r#trait) => format!("<{self_type} as {}>::{name}", render_path(r#trait)),
+                None => format!("{self_type}::{name}"),
+            }
+        }
+        _ => "_".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_parse_items() -> Result<(), crate::error::Error> {
+        let krate = serde_json::json!({
+            "root": 0,
+            "index": {
+                "1": {
+                    "name": "from_str",
+                    "docs": "Deserialize an instance of type `T` from a string of JSON text.\n\nMore details.",
+                    "deprecation": null,
+                    "attrs": [],
+                    "inner": {
+                        "function": {
+                            "sig": {
+                                "inputs": [["s", {"borrowed_ref": {"lifetime": "'a", "is_mutable": false, "type": {"primitive": "str"}}}]],
+                                "output": {"resolved_path": {"path": "Result", "id": 2, "args": {"angle_bracketed": {"args": [{"type": {"generic": "T"}}], "constraints": []}}}},
+                                "is_c_variadic": false
+                            },
+                            "generics": {
+                                "params": [
+                                    {"name": "'a", "kind": {"lifetime": {"outlives": []}}},
+                                    {"name": "T", "kind": {"type": {"bounds": [{"trait_bound": {"trait": {"path": "Deserialize", "id": 3, "args": {"angle_bracketed": {"args": [{"lifetime": "'a"}], "constraints": []}}}, "generic_params": [], "modifier": "none"}}], "default": null, "is_synthetic": false}}}
+                                ],
+                                "where_predicates": []
+                            },
+                            "header": {"is_const": false, "is_unsafe": false, "is_async": false, "abi": "Rust"},
+                            "has_body": true
+                        }
+                    }
+                },
+                "5": {
+                    "name": "SWAP",
+                    "docs": null,
+                    "deprecation": null,
+                    "attrs": [
+                        "#[stable(feature = \"rust1\", since = \"1.0.0\")]",
+                        "#[rustc_const_unstable(feature = \"const_swap\", issue = \"83163\")]"
+                    ],
+                    "inner": {"constant": {"type": {"primitive": "bool"}, "const": {"expr": "true", "value": null, "is_literal": true}}}
+                },
+                "6": {
+                    "name": "NEEDS_STABILIZATION",
+                    "docs": null,
+                    "deprecation": null,
+                    "attrs": [
+                        {"other": "#[attr = Stability { level: Unstable { reason: None, issue: None }, feature: \"demo\" }]"},
+                        {"other": "#[doc(hidden_stable_docs)]"}
+                    ],
+                    "inner": {"constant": {"type": {"primitive": "bool"}, "const": {"expr": "true", "value": null, "is_literal": true}}}
+                },
+                "4": {
+                    "name": "Value",
+                    "docs": null,
+                    "deprecation": {"since": "1.0.0", "note": "use something else"},
+                    "attrs": [],
+                    "inner": {"enum": {"generics": {"params": [], "where_predicates": []}}}
+                }
+            },
+            "paths": {
+                "0": {"crate_id": 0, "path": ["serde_json"], "kind": "module"},
+                "1": {"crate_id": 0, "path": ["serde_json", "de", "from_str"], "kind": "function"},
+                "3": {"crate_id": 1, "path": ["serde", "Deserialize"], "kind": "trait"},
+                "4": {"crate_id": 0, "path": ["serde_json", "Value"], "kind": "enum"},
+                "5": {"crate_id": 0, "path": ["serde_json", "SWAP"], "kind": "constant"},
+                "6": {"crate_id": 0, "path": ["serde_json", "NEEDS_STABILIZATION"], "kind": "constant"}
+            }
+        });
+
+        let items = super::parse_items(&krate)?;
+
+        assert_eq!(items.len(), 4);
+
+        // A stable item stays stable even though its const-ness is unstable.
+        let stability = |path: &str| {
+            items
+                .iter()
+                .find(|item| item.path.as_deref() == Some(path))
+                .and_then(|item| item.stability.as_deref())
+        };
+        assert_eq!(stability("SWAP"), Some("stable"));
+        assert_eq!(stability("NEEDS_STABILIZATION"), Some("unstable"));
+        assert_eq!(stability("Value"), None);
+
+        let value = items
+            .iter()
+            .find(|item| item.path.as_deref() == Some("Value"))
+            .unwrap();
+        assert_eq!(value.r#type, "Enums");
+        assert_eq!(value.href.as_deref(), Some("enum.Value.html"));
+        assert_eq!(value.signature.as_deref(), Some("pub enum Value"));
+        assert_eq!(
+            value.deprecation.as_ref().and_then(|d| d.since.as_deref()),
+            Some("1.0.0")
+        );
+
+        let from_str = items
+            .iter()
+            .find(|item| item.path.as_deref() == Some("de::from_str"))
+            .unwrap();
+        assert_eq!(from_str.r#type, "Functions");
+        assert_eq!(from_str.href.as_deref(), Some("de/fn.from_str.html"));
+        assert_eq!(from_str.path.as_deref(), Some("de::from_str"));
+        assert_eq!(
+            from_str.full_path.as_deref(),
+            Some("serde_json::de::from_str")
+        );
+        assert_eq!(
+            from_str.signature.as_deref(),
+            Some("pub fn from_str<'a, T: Deserialize<'a>>(s: &'a str) -> Result<T>")
+        );
+        assert_eq!(
+            from_str.summary.as_deref(),
+            Some("Deserialize an instance of type `T` from a string of JSON text.")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,793 @@
+/// Tantivy index over the items of one crate version.
+pub struct ItemIndex {
+    index: tantivy::Index,
+    reader: tantivy::IndexReader,
+}
+
+impl std::fmt::Debug for ItemIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemIndex")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ItemIndex {
+    /// Splits identifiers into their camelCase and snake_case words.
+    const SUBWORD_TOKENIZER: &'static str = "subword";
+
+    /// Character n-grams, which match substrings and misspellings the other fields miss.
+    const NGRAM_TOKENIZER: &'static str = "ngram";
+
+    fn schema() -> tantivy::schema::Schema {
+        let text_field = |tokenizer: &str| {
+            tantivy::schema::TextOptions::default().set_indexing_options(
+                tantivy::schema::TextFieldIndexing::default()
+                    .set_tokenizer(tokenizer)
+                    .set_index_option(tantivy::schema::IndexRecordOption::WithFreqsAndPositions),
+            )
+        };
+
+        let mut schema_builder = tantivy::schema::Schema::builder();
+        schema_builder.add_text_field("path", text_field(Self::SUBWORD_TOKENIZER));
+        schema_builder.add_text_field("path_ngram", text_field(Self::NGRAM_TOKENIZER));
+        schema_builder.add_text_field("summary", tantivy::schema::TEXT);
+        schema_builder.add_text_field("signature", tantivy::schema::TEXT);
+        schema_builder.add_text_field("item", tantivy::schema::STORED);
+        schema_builder.build()
+    }
+
+    /// Tokenizers are not persisted with the index and have to be registered on every open.
+    fn register_tokenizers(index: &tantivy::Index) -> Result<(), crate::error::Error> {
+        index.tokenizers().register(
+            Self::SUBWORD_TOKENIZER,
+            tantivy::tokenizer::TextAnalyzer::from(SubwordTokenizer::default()),
+        );
+        index.tokenizers().register(
+            Self::NGRAM_TOKENIZER,
+            tantivy::tokenizer::TextAnalyzer::builder(tantivy::tokenizer::NgramTokenizer::new(
+                2, 3, false,
+            )?)
+            .filter(tantivy::tokenizer::LowerCaser)
+            .build(),
+        );
+
+        Ok(())
+    }
+
+    fn from_index(
+        index: tantivy::Index,
+        items: Option<&[crate::entity::docs::Item]>,
+    ) -> Result<Self, crate::error::Error> {
+        Self::register_tokenizers(&index)?;
+
+        if let Some(items) = items {
+            let schema = index.schema();
+            let path_field = schema.get_field("path")?;
+            let path_ngram_field = schema.get_field("path_ngram")?;
+            let summary_field = schema.get_field("summary")?;
+            let signature_field = schema.get_field("signature")?;
+            let item_field = schema.get_field("item")?;
+
+            let mut index_writer: tantivy::IndexWriter = index.writer(50_000_000)?;
+
+            for item in items {
+                let mut doc = tantivy::TantivyDocument::default();
+                if let Some(path) = &item.path {
+                    doc.add_text(path_field, path);
+                    // Only the item's own name, n-grams of module paths would match everything.
+                    doc.add_text(path_ngram_field, path.rsplit("::").next().unwrap_or(path));
+                }
+                if let Some(summary) = &item.summary {
+                    doc.add_text(summary_field, summary);
+                }
+                if let Some(signature) = &item.signature {
+                    doc.add_text(signature_field, signature);
+                }
+                doc.add_text(item_field, serde_json::to_string(item).unwrap_or_default());
+                index_writer.add_document(doc)?;
+            }
+
+            index_writer.commit()?;
+        }
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(tantivy::ReloadPolicy::Manual)
+            .try_into()?;
+
+        Ok(Self { index, reader })
+    }
+
+    pub fn create_in_ram(items: &[crate::entity::docs::Item]) -> Result<Self, crate::error::Error> {
+        let index = tantivy::Index::create_in_ram(Self::schema());
+        Self::from_index(index, Some(items))
+    }
+
+    pub fn create_in_dir(
+        path: &std::path::Path,
+        items: &[crate::entity::docs::Item],
+    ) -> Result<Self, crate::error::Error> {
+        persist_index(path, Self::schema(), |index| {
+            Self::from_index(index, Some(items)).map(drop)
+        })?;
+
+        Self::open_in_dir(path)
+    }
+
+    pub fn open_in_dir(path: &std::path::Path) -> Result<Self, crate::error::Error> {
+        let index = tantivy::Index::open_in_dir(path)?;
+        Self::from_index(index, None)
+    }
+
+    fn tokens(
+        &self,
+        field: tantivy::schema::Field,
+        text: &str,
+    ) -> Result<Vec<String>, crate::error::Error> {
+        let mut analyzer = self.index.tokenizer_for_field(field)?;
+        let mut stream = analyzer.token_stream(text);
+
+        let mut tokens = Vec::new();
+        while let Some(token) = stream.next() {
+            if !tokens.contains(&token.text) {
+                tokens.push(token.text.clone());
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    /// Ranks items against `keyword`, tolerating typos, case and word-splitting differences:
+    /// `hashmap` and `HashMapp` find `HashMap`, `into_iter` finds `IntoIterator`.
+    ///
+    /// Words are matched exactly, by prefix and within a Levenshtein distance on the
+    /// camelCase / snake_case words of item paths, by character n-grams on item names,
+    /// and exactly on summaries and signatures. Name matches rank highest.
+    pub fn search(
+        &self,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::SearchHit>, crate::error::Error> {
+        use tantivy::query::Query;
+
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let schema = self.index.schema();
+        let path_field = schema.get_field("path")?;
+        let path_ngram_field = schema.get_field("path_ngram")?;
+        let summary_field = schema.get_field("summary")?;
+        let signature_field = schema.get_field("signature")?;
+        let item_field = schema.get_field("item")?;
+
+        let boosted =
+            |query: Box<dyn Query>, boost: f32| -> (tantivy::query::Occur, Box<dyn Query>) {
+                (
+                    tantivy::query::Occur::Should,
+                    Box::new(tantivy::query::BoostQuery::new(query, boost)),
+                )
+            };
+        let term_query = |field: tantivy::schema::Field, text: &str| -> Box<dyn Query> {
+            Box::new(tantivy::query::TermQuery::new(
+                tantivy::Term::from_field_text(field, text),
+                tantivy::schema::IndexRecordOption::WithFreqs,
+            ))
+        };
+
+        let mut clauses = Vec::new();
+
+        for word in self.tokens(path_field, keyword)? {
+            let term = tantivy::Term::from_field_text(path_field, &word);
+            let distance = match word.chars().count() {
+                0..=3 => 0,
+                4..=7 => 1,
+                _ => 2,
+            };
+
+            clauses.push(boosted(term_query(path_field, &word), 4.0));
+            if distance > 0 {
+                clauses.push(boosted(
+                    Box::new(tantivy::query::FuzzyTermQuery::new(
+                        term.clone(),
+                        distance,
+                        true,
+                    )),
+                    2.0,
+                ));
+            }
+            if word.chars().count() >= 3 {
+                clauses.push(boosted(
+                    Box::new(tantivy::query::FuzzyTermQuery::new_prefix(
+                        term,
+                        distance.min(1),
+                        true,
+                    )),
+                    1.0,
+                ));
+            }
+        }
+
+        for gram in self.tokens(path_ngram_field, keyword)? {
+            clauses.push(boosted(term_query(path_ngram_field, &gram), 0.2));
+        }
+
+        for field in [summary_field, signature_field] {
+            for word in self.tokens(field, keyword)? {
+                clauses.push(boosted(term_query(field, &word), 0.5));
+            }
+        }
+
+        let query = tantivy::query::BooleanQuery::new(clauses);
+        let searcher = self.reader.searcher();
+
+        let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(limit))?;
+
+        let mut hits = Vec::new();
+
+        for (score, doc_address) in top_docs {
+            use tantivy::schema::Value;
+
+            let retrieved_doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+
+            let item = retrieved_doc
+                .get_first(item_field)
+                .and_then(|v| v.as_str())
+                .and_then(|json| serde_json::from_str::<crate::entity::docs::Item>(json).ok());
+
+            if let Some(item) = item {
+                hits.push(crate::entity::docs::SearchHit { score, item });
+            }
+        }
+
+        Ok(hits)
+    }
+
+    /// Every indexed item, for searches that do not go through Tantivy queries.
+    pub fn items(&self) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
+        use tantivy::schema::Value;
+
+        let item_field = self.index.schema().get_field("item")?;
+        let searcher = self.reader.searcher();
+
+        let mut addresses = searcher
+            .search(
+                &tantivy::query::AllQuery,
+                &tantivy::collector::DocSetCollector,
+            )?
+            .into_iter()
+            .collect::<Vec<tantivy::DocAddress>>();
+        addresses.sort();
+
+        let mut items = Vec::with_capacity(addresses.len());
+
+        for address in addresses {
+            let doc: tantivy::TantivyDocument = searcher.doc(address)?;
+
+            let item = doc
+                .get_first(item_field)
+                .and_then(|v| v.as_str())
+                .and_then(|json| serde_json::from_str::<crate::entity::docs::Item>(json).ok());
+
+            if let Some(item) = item {
+                items.push(item);
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+/// Similarity of a query to the name of an item, independent of the index the item was
+/// found in, from 0 (unrelated) to 1 (same name). Case, underscores and spaces are ignored,
+/// and a query such as `body::Bytes` is compared with as many trailing path segments.
+pub(super) fn name_similarity(query: &str, path: &str) -> f32 {
+    let normalize = |text: &str| {
+        text.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<Vec<char>>()
+    };
+
+    let segments = path.split("::").collect::<Vec<&str>>();
+    let query_segments = query.split("::").count().min(segments.len());
+    let name = segments[segments.len() - query_segments..].join("::");
+
+    let (query, name) = (normalize(query), normalize(&name));
+    if query.is_empty() || name.is_empty() {
+        return 0.0;
+    }
+    if query == name {
+        return 1.0;
+    }
+
+    let (shorter, longer) = if query.len() <= name.len() {
+        (&query, &name)
+    } else {
+        (&name, &query)
+    };
+    let length_ratio = shorter.len() as f32 / longer.len() as f32;
+
+    if longer
+        .windows(shorter.len())
+        .any(|window| window == shorter.as_slice())
+    {
+        return 0.5 + 0.4 * length_ratio;
+    }
+
+    let distance = levenshtein(&query, &name);
+    (0.8 * (1.0 - distance as f32 / longer.len() as f32)).max(0.0)
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<usize>>();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+impl CachedIndex for ItemIndex {
+    type Source = Vec<crate::entity::docs::Item>;
+
+    const SCHEMA_VERSION: &'static str = "v3";
+
+    fn create_in_ram(items: &Self::Source) -> Result<Self, crate::error::Error> {
+        Self::create_in_ram(items)
+    }
+
+    fn create_in_dir(
+        path: &std::path::Path,
+        items: &Self::Source,
+    ) -> Result<Self, crate::error::Error> {
+        Self::create_in_dir(path, items)
+    }
+
+    fn open_in_dir(path: &std::path::Path) -> Result<Self, crate::error::Error> {
+        Self::open_in_dir(path)
+    }
+}
+
+/// Builds an index in a temporary directory next to `path` and moves it into place,
+/// so that a crashed build never leaves a half-written index behind.
+pub(super) fn persist_index(
+    path: &std::path::Path,
+    schema: tantivy::schema::Schema,
+    build: impl FnOnce(tantivy::Index) -> Result<(), crate::error::Error>,
+) -> Result<(), crate::error::Error> {
+    let parent = path.parent().unwrap_or(path);
+
+    let tmp_dir = std::fs::create_dir_all(parent)
+        .and_then(|_| tempfile::tempdir_in(parent))
+        .map_err(|e| {
+            tracing::error!("{}", e);
+            crate::error::Error::CreateTempDir(e.to_string())
+        })?;
+
+    let index = tantivy::Index::create_in_dir(tmp_dir.path(), schema)?;
+    build(index)?;
+
+    let tmp_path = tmp_dir.keep();
+    if let Err(e) = std::fs::rename(&tmp_path, path) {
+        // Another task may have built the same index concurrently.
+        tracing::warn!("Failed to persist index {}: {}", path.display(), e);
+        let _ = std::fs::remove_dir_all(&tmp_path);
+    }
+
+    Ok(())
+}
+
+/// Splits text into lowercase words at non-alphanumeric characters, underscores and
+/// camelCase boundaries. Identifiers made of several words are also emitted joined,
+/// so that `IntoIterator` yields `into`, `iterator` and `intoiterator`.
+#[derive(Debug, Clone, Default)]
+pub struct SubwordTokenizer {
+    tokens: Vec<tantivy::tokenizer::Token>,
+}
+
+/// Tokens produced by [`SubwordTokenizer`].
+pub struct SubwordTokenStream<'a> {
+    tokens: &'a mut [tantivy::tokenizer::Token],
+    index: Option<usize>,
+}
+
+impl tantivy::tokenizer::Tokenizer for SubwordTokenizer {
+    type TokenStream<'a> = SubwordTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> SubwordTokenStream<'a> {
+        self.tokens.clear();
+
+        let mut position = 0;
+
+        let identifiers = text
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|identifier| !identifier.is_empty());
+
+        for identifier in identifiers {
+            let offset = identifier.as_ptr() as usize - text.as_ptr() as usize;
+            let words = subwords(identifier);
+
+            if words.len() > 1 {
+                self.tokens.push(tantivy::tokenizer::Token {
+                    offset_from: offset,
+                    offset_to: offset + identifier.len(),
+                    position,
+                    text: words.iter().map(|(_, word)| word.to_lowercase()).collect(),
+                    position_length: words.len(),
+                });
+            }
+
+            for (start, word) in words {
+                self.tokens.push(tantivy::tokenizer::Token {
+                    offset_from: offset + start,
+                    offset_to: offset + start + word.len(),
+                    position,
+                    text: word.to_lowercase(),
+                    position_length: 1,
+                });
+                position += 1;
+            }
+        }
+
+        SubwordTokenStream {
+            tokens: &mut self.tokens,
+            index: None,
+        }
+    }
+}
+
+impl tantivy::tokenizer::TokenStream for SubwordTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let next = self.index.map_or(0, |index| index + 1);
+        self.index = Some(next);
+        next < self.tokens.len()
+    }
+
+    fn token(&self) -> &tantivy::tokenizer::Token {
+        &self.tokens[self.index.unwrap_or_default()]
+    }
+
+    fn token_mut(&mut self) -> &mut tantivy::tokenizer::Token {
+        &mut self.tokens[self.index.unwrap_or_default()]
+    }
+}
+
+/// Words of an identifier with their byte offsets: `HTTPServer_v2` is `HTTP`, `Server`, `v2`.
+fn subwords(identifier: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    let chars = identifier.char_indices().collect::<Vec<(usize, char)>>();
+
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if let Some(start) = start.take() {
+                words.push((start, &identifier[start..offset]));
+            }
+            continue;
+        }
+
+        let Some(word_start) = start else {
+            start = Some(offset);
+            continue;
+        };
+
+        let previous = chars[i - 1].1;
+        let next = chars.get(i + 1).map(|&(_, c)| c);
+        let is_boundary = c.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next.is_some_and(char::is_lowercase)));
+
+        if is_boundary {
+            words.push((word_start, &identifier[word_start..offset]));
+            start = Some(offset);
+        }
+    }
+
+    if let Some(start) = start {
+        words.push((start, &identifier[start..]));
+    }
+
+    words
+}
+
+/// An index [`SearchIndexCache`] can build from fetched documentation, persist and reopen.
+pub trait CachedIndex: Sized + Send + Sync + 'static {
+    /// What the index is built from.
+    type Source: Send + 'static;
+
+    /// Directory of the persisted indexes. Bump when the schema changes
+    /// so that stale on-disk indexes are not reopened.
+    const SCHEMA_VERSION: &'static str;
+
+    fn create_in_ram(source: &Self::Source) -> Result<Self, crate::error::Error>;
+
+    fn create_in_dir(
+        path: &std::path::Path,
+        source: &Self::Source,
+    ) -> Result<Self, crate::error::Error>;
+
+    fn open_in_dir(path: &std::path::Path) -> Result<Self, crate::error::Error>;
+}
+
+/// Keeps built indexes around so that repeated searches do not refetch docs.rs.
+///
+/// Indexes of concrete versions are persisted under `dir` and survive restarts.
+/// Indexes of mutable versions such as `latest` only live in memory and expire after `ttl`.
+/// At most `capacity` indexes are kept open, the least recently used one is dropped first.
+#[derive(Debug)]
+pub struct SearchIndexCache<T = ItemIndex> {
+    dir: Option<std::path::PathBuf>,
+    capacity: usize,
+    ttl: std::time::Duration,
+    entries: std::sync::Mutex<Vec<SearchIndexCacheEntry<T>>>,
+}
+
+type SearchIndexCell<T> = std::sync::Arc<tokio::sync::OnceCell<std::sync::Arc<T>>>;
+
+#[derive(Debug)]
+struct SearchIndexCacheEntry<T> {
+    crate_name: String,
+    version: String,
+    created_at: std::time::Instant,
+    index: SearchIndexCell<T>,
+}
+
+impl<T: CachedIndex> SearchIndexCache<T> {
+    pub fn new(config: &crate::config::SearchIndexConfig) -> Self {
+        Self {
+            dir: config.persistent.then(|| config.dir.clone()),
+            capacity: config.capacity.max(1),
+            ttl: config.ttl,
+            entries: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn is_immutable(version: &str) -> bool {
+        semver::Version::parse(version).is_ok()
+    }
+
+    fn persistent_path(&self, crate_name: &str, version: &str) -> Option<std::path::PathBuf> {
+        let dir = self.dir.as_ref()?;
+
+        Self::is_immutable(version)
+            .then(|| dir.join(T::SCHEMA_VERSION).join(crate_name).join(version))
+    }
+
+    fn cell(&self, crate_name: &str, version: &str) -> SearchIndexCell<T> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        entries.retain(|entry| {
+            Self::is_immutable(&entry.version) || entry.created_at.elapsed() < self.ttl
+        });
+
+        let position = entries
+            .iter()
+            .position(|entry| entry.crate_name == crate_name && entry.version == version);
+
+        let entry = match position {
+            Some(position) => entries.remove(position),
+            None => SearchIndexCacheEntry {
+                crate_name: crate_name.to_owned(),
+                version: version.to_owned(),
+                created_at: std::time::Instant::now(),
+                index: Default::default(),
+            },
+        };
+
+        let cell = entry.index.clone();
+
+        entries.push(entry);
+        if entries.len() > self.capacity {
+            entries.remove(0);
+        }
+
+        cell
+    }
+
+    /// Returns the index for a crate version, building it from `fetch_source` on a cache miss.
+    pub async fn get_or_build<F, Fut>(
+        &self,
+        crate_name: &str,
+        version: &str,
+        fetch_source: F,
+    ) -> Result<std::sync::Arc<T>, crate::error::Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T::Source, crate::error::Error>>,
+    {
+        let cell = self.cell(crate_name, version);
+
+        let index = cell
+            .get_or_try_init(|| async {
+                let path = self.persistent_path(crate_name, version);
+
+                if let Some(path) = &path
+                    && path.exists()
+                {
+                    match T::open_in_dir(path) {
+                        Ok(index) => return Ok(std::sync::Arc::new(index)),
+                        Err(e) => {
+                            tracing::warn!("Rebuilding index {}: {}", path.display(), e);
+                            let _ = std::fs::remove_dir_all(path);
+                        }
+                    }
+                }
+
+                let source = fetch_source().await?;
+
+                // Indexing thousands of documents would stall the runtime's worker thread.
+                let index = tokio::task::spawn_blocking(move || match &path {
+                    Some(path) => T::create_in_dir(path, &source),
+                    None => T::create_in_ram(&source),
+                })
+                .await
+                .map_err(|e| tantivy::TantivyError::ErrorInThread(e.to_string()))??;
+
+                Ok::<_, crate::error::Error>(std::sync::Arc::new(index))
+            })
+            .await?;
+
+        Ok(index.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn items() -> Vec<crate::entity::docs::Item> {
+        [
+            "de::Deserialize",
+            "ser::Serialize",
+            "de::value::BoolDeserializer",
+        ]
+        .into_iter()
+        .map(|path| crate::entity::docs::Item {
+            r#type: "Traits".to_owned(),
+            path: Some(path.to_owned()),
+            ..Default::default()
+        })
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_or_build() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let config = crate::config::SearchIndexConfig {
+            persistent: true,
+            dir: dir.path().to_owned(),
+            capacity: 4,
+            ttl: std::time::Duration::from_secs(60),
+        };
+
+        let cache = super::SearchIndexCache::<super::ItemIndex>::new(&config);
+        let index = cache
+            .get_or_build("serde", "1.0.228", || async { Ok(items()) })
+            .await?;
+        let result = index.search("Deserialize", 10)?;
+        assert_eq!(result[0].item.path.as_deref(), Some("de::Deserialize"));
+
+        // A fresh cache reopens the persisted index instead of fetching items again.
+        let cache = super::SearchIndexCache::<super::ItemIndex>::new(&config);
+        let index = cache
+            .get_or_build("serde", "1.0.228", || async {
+                Err(crate::error::Error::Http("unexpected fetch".to_owned()))
+            })
+            .await?;
+        let result = index.search("Serialize", 10)?;
+        assert_eq!(result[0].item.path.as_deref(), Some("ser::Serialize"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_summary_and_signature() -> Result<(), crate::error::Error> {
+        let mut items = items();
+        items.push(crate::entity::docs::Item {
+            r#type: "Functions".to_owned(),
+            path: Some("time::sleep".to_owned()),
+            signature: Some("fn sleep(Instant) -> Result<Duration>".to_owned()),
+            summary: Some("Waits until the deadline has elapsed.".to_owned()),
+            ..Default::default()
+        });
+
+        let index = super::ItemIndex::create_in_ram(&items)?;
+
+        let result = index.search("returns Result<Duration>", 10)?;
+        assert_eq!(result[0].item.path.as_deref(), Some("time::sleep"));
+
+        let result = index.search("deadline", 10)?;
+        assert_eq!(result[0].item.path.as_deref(), Some("time::sleep"));
+
+        assert_eq!(index.items()?.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuzzy_search() -> Result<(), crate::error::Error> {
+        let items = [
+            "collections::HashMap",
+            "collections::HashSet",
+            "collections::hash_map::Entry",
+            "iter::IntoIterator",
+            "iter::Iterator",
+            "vec::IntoIter",
+        ]
+        .into_iter()
+        .map(|path| crate::entity::docs::Item {
+            r#type: "Structs".to_owned(),
+            path: Some(path.to_owned()),
+            ..Default::default()
+        })
+        .collect::<Vec<crate::entity::docs::Item>>();
+
+        let index = super::ItemIndex::create_in_ram(&items)?;
+        let top = |keyword: &str| -> Result<Option<String>, crate::error::Error> {
+            Ok(index
+                .search(keyword, 10)?
+                .into_iter()
+                .next()
+                .and_then(|hit| hit.item.path))
+        };
+
+        assert_eq!(top("HashMap")?.as_deref(), Some("collections::HashMap"));
+        assert_eq!(top("hashmap")?.as_deref(), Some("collections::HashMap"));
+        assert_eq!(top("HashMapp")?.as_deref(), Some("collections::HashMap"));
+        assert_eq!(top("hash map")?.as_deref(), Some("collections::HashMap"));
+        assert_eq!(top("IntoIterator")?.as_deref(), Some("iter::IntoIterator"));
+        assert_eq!(top("into_iterator")?.as_deref(), Some("iter::IntoIterator"));
+        assert_eq!(top("Itreator")?.as_deref(), Some("iter::Iterator"));
+
+        let hits = index.search("hash", 2)?;
+        assert_eq!(hits.len(), 2);
+        assert!(hits[0].score >= hits[1].score);
+
+        // Tantivy panics on a limit of 0.
+        assert!(index.search("hash", 0)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subwords() {
+        let words = |identifier: &'static str| {
+            super::subwords(identifier)
+                .into_iter()
+                .map(|(_, word)| word)
+                .collect::<Vec<&str>>()
+        };
+
+        assert_eq!(words("IntoIterator"), ["Into", "Iterator"]);
+        assert_eq!(words("into_iter"), ["into", "iter"]);
+        assert_eq!(words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(words("Utf8Error"), ["Utf8", "Error"]);
+        assert_eq!(words("__private"), ["private"]);
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(super::name_similarity("Bytes", "Bytes"), 1.0);
+        assert_eq!(super::name_similarity("bytes", "body::Bytes"), 1.0);
+        assert_eq!(
+            super::name_similarity("hash map", "collections::HashMap"),
+            1.0
+        );
+        assert_eq!(super::name_similarity("body::Bytes", "body::Bytes"), 1.0);
+        assert!(super::name_similarity("body::Bytes", "buf::Bytes") < 1.0);
+
+        let exact = super::name_similarity("Bytes", "Bytes");
+        let contains = super::name_similarity("Bytes", "BytesMut");
+        let typo = super::name_similarity("Bytes", "Bites");
+        let unrelated = super::name_similarity("Bytes", "Runtime");
+        assert!(exact > contains && contains > typo && typo > unrelated);
+    }
+}
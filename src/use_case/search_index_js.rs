@@ -0,0 +1,718 @@
+// rustdoc's `search-index.js` is an implementation detail of its search page and
+// has changed shape several times. This reads the `JSON.parse` layouts written by
+// rustdoc 1.60 to 1.90: a per-crate object before 1.74 and a `Map` of crates since,
+// with inline descriptions (`d`) before 1.78 and descriptions sharded into
+// `search.desc/` since. rustdoc 1.91 replaced the file with a `search.index/`
+// directory, which is not read; such builds fall back to the item list, without
+// descriptions or signatures. Unknown fields degrade to missing data instead of errors.
+type Json = serde_json::Value;
+
+/// File names of the search index of one rustdoc build.
+#[derive(Debug, Default)]
+pub(super) struct SearchIndexFiles {
+    resource_suffix: String,
+    search_index: Option<String>,
+}
+
+impl SearchIndexFiles {
+    /// Reads the file names from the `rustdoc-vars` of any documentation page.
+    pub(super) fn from_html(html: &str) -> Self {
+        let document = scraper::Html::parse_document(html);
+        let Ok(selector) = scraper::Selector::parse(r#"meta[name="rustdoc-vars"], #rustdoc-vars"#)
+        else {
+            return Self::default();
+        };
+
+        let Some(vars) = document.select(&selector).next() else {
+            return Self::default();
+        };
+
+        // Both end up in file names, so anything that could escape the doc root is ignored.
+        let is_file_name = |name: &&str| {
+            !name.contains("..")
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        };
+
+        Self {
+            resource_suffix: vars
+                .attr("data-resource-suffix")
+                .filter(is_file_name)
+                .unwrap_or_default()
+                .to_owned(),
+            search_index: vars
+                .attr("data-search-index-js")
+                .and_then(|path| path.rsplit('/').next())
+                .filter(is_file_name)
+                .map(str::to_owned),
+        }
+    }
+
+    /// `search-index.js` relative to the doc root.
+    pub(super) fn search_index(&self) -> String {
+        self.search_index
+            .clone()
+            .unwrap_or_else(|| format!("search-index{}.js", self.resource_suffix))
+    }
+
+    /// Description shard `shard` of a crate relative to the doc root.
+    pub(super) fn desc_shard(&self, crate_name: &str, shard: usize) -> String {
+        format!(
+            "search.desc/{crate_name}/{crate_name}-desc-{shard}-{}.js",
+            self.resource_suffix
+        )
+    }
+}
+
+/// Extracts the search data of `crate_name` from a `search-index.js` file.
+pub(super) fn parse_crate_corpus(js: &str, crate_name: &str) -> Result<Json, crate::error::Error> {
+    let literal = js
+        .find("JSON.parse('")
+        .map(|start| unescape_js_string(&js[start + "JSON.parse('".len()..]))
+        .ok_or_else(|| {
+            crate::error::Error::SearchIndexJs(
+                "Unsupported search index format, only rustdoc 1.60 to 1.90 are supported"
+                    .to_owned(),
+            )
+        })?;
+
+    let index = serde_json::from_str::<Json>(&literal).map_err(|e| {
+        tracing::error!("{}", e);
+        crate::error::Error::SearchIndexJs(e.to_string())
+    })?;
+
+    let corpus = match index {
+        // rustdoc < 1.74: `{"crate": {...}}`
+        Json::Object(mut crates) => crates.remove(crate_name),
+        // rustdoc >= 1.74: `new Map([["crate", {...}]])`
+        Json::Array(crates) => crates.into_iter().find_map(|entry| match entry {
+            Json::Array(pair) if pair.first().and_then(Json::as_str) == Some(crate_name) => {
+                pair.into_iter().nth(1)
+            }
+            _ => None,
+        }),
+        _ => None,
+    };
+
+    corpus.ok_or_else(|| {
+        crate::error::Error::SearchIndexJs(format!("{crate_name} is not in the search index"))
+    })
+}
+
+/// Number of `search.desc/` shards the descriptions of a crate are split into.
+/// Zero for indexes that carry their descriptions inline.
+pub(super) fn desc_shard_count(corpus: &Json) -> usize {
+    match corpus.get("D").and_then(Json::as_str) {
+        Some(lengths) => VlqHexDecoder::new(lengths).count(),
+        None => 0,
+    }
+}
+
+/// Reads the descriptions of a `search.desc/{crate}/{crate}-desc-{n}-{suffix}.js` shard.
+pub(super) fn parse_desc_shard(js: &str) -> Result<Vec<String>, crate::error::Error> {
+    let args = js
+        .find("loadedDescShard(")
+        .map(|start| start + "loadedDescShard(".len())
+        .zip(js.rfind(')'))
+        .and_then(|(start, end)| js.get(start..end))
+        .ok_or_else(|| {
+            crate::error::Error::SearchIndexJs("Missing description shard".to_owned())
+        })?;
+
+    // The arguments `"crate", 0, "desc\ndesc"` are valid JSON once wrapped in brackets.
+    let args = serde_json::from_str::<Json>(&format!("[{args}]")).map_err(|e| {
+        tracing::error!("{}", e);
+        crate::error::Error::SearchIndexJs(e.to_string())
+    })?;
+
+    let descs = args
+        .get(2)
+        .and_then(Json::as_str)
+        .ok_or_else(|| crate::error::Error::SearchIndexJs("Missing descriptions".to_owned()))?;
+
+    Ok(descs.split('\n').map(str::to_owned).collect())
+}
+
+/// Lists the items of a crate, including methods, fields and variants, with their
+/// descriptions and function signatures.
+///
+/// `descs` are the concatenated description shards, empty for inline descriptions.
+pub(super) fn parse_items(
+    crate_name: &str,
+    corpus: &Json,
+    descs: &[String],
+) -> Result<Vec<crate::entity::docs::Item>, crate::error::Error> {
+    let names = corpus
+        .get("n")
+        .and_then(Json::as_array)
+        .ok_or_else(|| crate::error::Error::SearchIndexJs("Missing `n` table".to_owned()))?
+        .iter()
+        .map(|name| name.as_str().unwrap_or_default())
+        .collect::<Vec<&str>>();
+
+    let types = match corpus.get("t") {
+        Some(Json::String(types)) => types.chars().map(|c| c as i64 - 'A' as i64).collect(),
+        Some(types) => numbers(types),
+        None => Vec::new(),
+    };
+    let parents = corpus.get("i").map(numbers).unwrap_or_default();
+    let paths = module_paths(corpus.get("q"), names.len(), crate_name);
+    let parent_types = corpus
+        .get("p")
+        .and_then(Json::as_array)
+        .map(|parents| parents.iter().map(parent_type).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let type_names = parent_types
+        .iter()
+        .map(|parent| parent.map(|(_, name)| name).unwrap_or("_"))
+        .collect::<Vec<&str>>();
+    let functions = match corpus.get("f").and_then(Json::as_str) {
+        Some(functions) => VlqHexDecoder::new(functions).collect::<Vec<Vlq>>(),
+        None => Vec::new(),
+    };
+    let descs = descriptions(corpus, descs, names.len());
+    let deprecated = corpus
+        .get("c")
+        .and_then(Json::as_str)
+        .map(roaring_bitmap)
+        .unwrap_or_default();
+
+    let items = names
+        .iter()
+        .enumerate()
+        .filter_map(|(i, name)| {
+            let ty = *types.get(i)?;
+            let modules = paths.get(i).map(Vec::as_slice).unwrap_or_default();
+
+            let parent = parents
+                .get(i)
+                .filter(|&&parent| parent > 0)
+                .and_then(|&parent| *parent_types.get(parent as usize - 1)?);
+
+            let (r#type, kind, href, path) = match parent {
+                Some((parent_ty, parent_name)) => {
+                    let (r#type, kind, anchor) = member_kind(ty)?;
+                    let (_, _, parent_href) = item_kind(parent_ty, parent_name, modules)?;
+                    let path = join_path(modules, &[parent_name, name]);
+                    (r#type, kind, format!("{parent_href}#{anchor}.{name}"), path)
+                }
+                None => {
+                    let (r#type, kind, href) = item_kind(ty, name, modules)?;
+                    (r#type, kind, href, join_path(modules, &[name]))
+                }
+            };
+
+            let signature = functions
+                .get(i)
+                .and_then(|function| render_signature(name, function, &type_names));
+
+            Some(crate::entity::docs::Item {
+                r#type: r#type.to_owned(),
+                href: Some(href),
+                full_path: Some(format!("{crate_name}::{path}")),
+                path: Some(path),
+                kind: Some(kind.to_owned()),
+                signature,
+                summary: descs.get(i).cloned().flatten(),
+                deprecation: deprecated
+                    .contains(&(i as u32 + 1))
+                    .then(crate::entity::docs::Deprecation::default),
+                stability: None,
+            })
+        })
+        .collect::<Vec<crate::entity::docs::Item>>();
+
+    Ok(items)
+}
+
+/// Reads a JavaScript single-quoted string literal up to its closing quote.
+fn unescape_js_string(js: &str) -> String {
+    let mut result = String::with_capacity(js.len());
+    let mut chars = js.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => break,
+            '\\' => match chars.next() {
+                // Line continuation.
+                Some('\n') | None => {}
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some('t') => result.push('\t'),
+                Some('u') => {
+                    let hex = chars.by_ref().take(4).collect::<String>();
+                    if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        result.push(c);
+                    }
+                }
+                Some(c) => result.push(c),
+            },
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// A number list that is either a JSON array or a VLQ-hex string.
+fn numbers(value: &Json) -> Vec<i64> {
+    match value {
+        Json::String(encoded) => VlqHexDecoder::new(encoded)
+            .map(|value| match value {
+                Vlq::Num(n) => n,
+                Vlq::List(_) => 0,
+            })
+            .collect(),
+        Json::Array(values) => values
+            .iter()
+            .map(|value| value.as_i64().unwrap_or_default())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Module path of every item without the crate name, from the sparse `q` table.
+fn module_paths(q: Option<&Json>, len: usize, crate_name: &str) -> Vec<Vec<String>> {
+    let mut changes = std::collections::HashMap::new();
+
+    for (i, entry) in q.and_then(Json::as_array).into_iter().flatten().enumerate() {
+        match entry {
+            // `[index, path]`, the path applies until the next entry.
+            Json::Array(pair) => {
+                if let (Some(index), Some(path)) = (
+                    pair.first().and_then(Json::as_u64),
+                    pair.get(1).and_then(Json::as_str),
+                ) {
+                    changes.insert(index as usize, path);
+                }
+            }
+            // One path per item, empty when unchanged.
+            Json::String(path) if !path.is_empty() => {
+                changes.insert(i, path.as_str());
+            }
+            _ => {}
+        }
+    }
+
+    let mut current = Vec::new();
+
+    (0..len)
+        .map(|i| {
+            if let Some(path) = changes.get(&i) {
+                current = path
+                    .split("::")
+                    .skip_while(|segment| *segment == crate_name)
+                    .map(str::to_owned)
+                    .collect();
+            }
+            current.clone()
+        })
+        .collect()
+}
+
+/// `[type, name, ...]` entry of the `p` table.
+fn parent_type(entry: &Json) -> Option<(i64, &str)> {
+    let ty = match entry.get(0)? {
+        Json::String(ty) => ty.chars().next()? as i64 - 'A' as i64,
+        ty => ty.as_i64()?,
+    };
+
+    Some((ty, entry.get(1)?.as_str()?))
+}
+
+fn join_path(modules: &[String], names: &[&str]) -> String {
+    modules
+        .iter()
+        .map(String::as_str)
+        .chain(names.iter().copied())
+        .collect::<Vec<&str>>()
+        .join("::")
+}
+
+/// `all.html` heading, rustdoc JSON kind and page of a top-level item.
+fn item_kind(
+    ty: i64,
+    name: &str,
+    modules: &[String],
+) -> Option<(&'static str, &'static str, String)> {
+    let dir = modules
+        .iter()
+        .map(|module| format!("{module}/"))
+        .collect::<String>();
+
+    let kind = match ty {
+        0 => return Some(("Modules", "module", format!("{dir}{name}/index.html"))),
+        21 => return Some(("Keywords", "keyword", format!("{dir}keyword.{name}.html"))),
+        3 => "struct",
+        4 => "enum",
+        5 => "function",
+        6 => "type_alias",
+        7 => "static",
+        8 => "trait",
+        14 => "macro",
+        15 => "primitive",
+        17 => "constant",
+        19 => "union",
+        20 => "extern_type",
+        23 => "proc_attribute",
+        24 => "proc_derive",
+        25 => "trait_alias",
+        _ => return None,
+    };
+
+    let (heading, prefix) = super::rustdoc_json::item_kind_info(kind)?;
+
+    Some((heading, kind, format!("{dir}{prefix}{name}.html")))
+}
+
+/// Heading, kind and anchor prefix of an item documented on its parent's page.
+fn member_kind(ty: i64) -> Option<(&'static str, &'static str, &'static str)> {
+    let kind = match ty {
+        10 => ("Methods", "method", "tymethod"),
+        11 => ("Methods", "method", "method"),
+        12 => ("Fields", "struct_field", "structfield"),
+        13 => ("Variants", "variant", "variant"),
+        16 => ("Associated Types", "assoc_type", "associatedtype"),
+        17 | 18 => ("Associated Constants", "assoc_const", "associatedconstant"),
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+/// Description of every item, `None` when it has none.
+fn descriptions(corpus: &Json, shards: &[String], len: usize) -> Vec<Option<String>> {
+    let summary = |desc: &str| {
+        let text = scraper::Html::parse_fragment(desc)
+            .root_element()
+            .text()
+            .collect::<String>();
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_owned())
+    };
+
+    // Older indexes carry the descriptions inline.
+    if let Some(descs) = corpus.get("d").and_then(Json::as_array) {
+        return descs
+            .iter()
+            .map(|desc| desc.as_str().and_then(summary))
+            .collect();
+    }
+
+    let Some(empty) = corpus.get("e").and_then(Json::as_str).map(roaring_bitmap) else {
+        return Vec::new();
+    };
+
+    // Shards only list non-empty descriptions. Bit 0 is the crate itself.
+    let mut shards = shards.iter();
+    if !empty.contains(&0) {
+        shards.next();
+    }
+
+    (0..len)
+        .map(|i| {
+            if empty.contains(&(i as u32 + 1)) {
+                None
+            } else {
+                shards.next().and_then(|desc| summary(desc))
+            }
+        })
+        .collect()
+}
+
+/// Decodes a base64 roaring bitmap as serialized by rustdoc.
+fn roaring_bitmap(encoded: &str) -> std::collections::HashSet<u32> {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()
+        .and_then(|bytes| parse_roaring_bitmap(&bytes))
+        .unwrap_or_default()
+}
+
+fn parse_roaring_bitmap(bytes: &[u8]) -> Option<std::collections::HashSet<u32>> {
+    let u16_at = |i: usize| -> Option<u32> {
+        Some(u16::from_le_bytes([*bytes.get(i)?, *bytes.get(i + 1)?]) as u32)
+    };
+
+    let has_runs = *bytes.first()? == 0x3b;
+    let (size, mut i) = if has_runs {
+        (u16_at(2)? as usize + 1, 4)
+    } else {
+        (
+            u32::from_le_bytes(bytes.get(4..8)?.try_into().ok()?) as usize,
+            8,
+        )
+    };
+
+    let is_run = if has_runs {
+        let len = size.div_ceil(8);
+        let is_run = bytes.get(i..i + len)?;
+        i += len;
+        is_run
+    } else {
+        &[]
+    };
+
+    let mut containers = Vec::with_capacity(size);
+    for _ in 0..size {
+        containers.push((u16_at(i)? << 16, u16_at(i + 2)? + 1));
+        i += 4;
+    }
+
+    // Container offsets, which are redundant for sequential reading.
+    if !has_runs || size >= 4 {
+        i += 4 * size;
+    }
+
+    let mut values = std::collections::HashSet::new();
+
+    for (j, (high, cardinality)) in containers.into_iter().enumerate() {
+        if is_run
+            .get(j / 8)
+            .is_some_and(|bits| bits & (1 << (j % 8)) != 0)
+        {
+            let runs = u16_at(i)?;
+            i += 2;
+            for _ in 0..runs {
+                let (start, length) = (u16_at(i)?, u16_at(i + 2)?);
+                i += 4;
+                values.extend((start..=start + length).map(|low| high | low));
+            }
+        } else if cardinality >= 4096 {
+            let bits = bytes.get(i..i + 8192)?;
+            i += 8192;
+            for (byte_index, byte) in bits.iter().enumerate() {
+                for bit in 0..8 {
+                    if byte & (1 << bit) != 0 {
+                        values.insert(high | (byte_index * 8 + bit) as u32);
+                    }
+                }
+            }
+        } else {
+            for _ in 0..cardinality {
+                values.insert(high | u16_at(i)?);
+                i += 2;
+            }
+        }
+    }
+
+    Some(values)
+}
+
+/// A value of rustdoc's VLQ-hex encoding: a number or a `{...}` list.
+#[derive(Debug, Clone, PartialEq)]
+enum Vlq {
+    Num(i64),
+    List(Vec<Vlq>),
+}
+
+/// Decodes rustdoc's VLQ-hex streams (`f`, `i`, `D`).
+///
+/// Numbers are zig-zag encoded hex digits, `@`-`O` for leading and `` ` ``-`o` for the last
+/// one. `{`/`}` delimit lists, `0`-`?` refer back to one of the last 16 top-level values.
+struct VlqHexDecoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    backrefs: std::collections::VecDeque<Vlq>,
+}
+
+impl<'a> VlqHexDecoder<'a> {
+    fn new(encoded: &'a str) -> Self {
+        Self {
+            bytes: encoded.as_bytes(),
+            offset: 0,
+            backrefs: Default::default(),
+        }
+    }
+
+    fn decode(&mut self) -> Option<Vlq> {
+        if *self.bytes.get(self.offset)? == b'{' {
+            self.offset += 1;
+            let mut list = Vec::new();
+            while *self.bytes.get(self.offset)? != b'}' {
+                list.push(self.decode()?);
+            }
+            self.offset += 1;
+            return Some(Vlq::List(list));
+        }
+
+        let mut n: i64 = 0;
+        loop {
+            let c = *self.bytes.get(self.offset)?;
+            self.offset += 1;
+            n = (n << 4) | (c & 0xF) as i64;
+            if c >= b'`' {
+                break;
+            }
+        }
+
+        Some(Vlq::Num(if n & 1 == 1 { -(n >> 1) } else { n >> 1 }))
+    }
+}
+
+impl Iterator for VlqHexDecoder<'_> {
+    type Item = Vlq;
+
+    fn next(&mut self) -> Option<Vlq> {
+        let c = *self.bytes.get(self.offset)?;
+
+        if (b'0'..b'@').contains(&c) {
+            self.offset += 1;
+            return self.backrefs.get((c - b'0') as usize).cloned();
+        }
+
+        if c == b'`' {
+            self.offset += 1;
+            return Some(Vlq::Num(0));
+        }
+
+        let value = self.decode()?;
+        self.backrefs.push_front(value.clone());
+        self.backrefs.truncate(16);
+
+        Some(value)
+    }
+}
+
+/// Renders `fn name(Inputs) -> Output` from an `f` entry. Generic parameters are shown as `_`.
+fn render_signature(name: &str, function: &Vlq, type_names: &[&str]) -> Option<String> {
+    let Vlq::List(parts) = function else {
+        return None;
+    };
+
+    let types = |node: &Vlq| match node {
+        Vlq::List(types) => types
+            .iter()
+            .map(|ty| render_type(ty, type_names))
+            .collect::<Vec<String>>(),
+        ty => vec![render_type(ty, type_names)],
+    };
+
+    let inputs = types(parts.first()?).join(", ");
+
+    match parts.get(1).map(types).as_deref() {
+        None | Some([]) => Some(format!("fn {name}({inputs})")),
+        Some([output]) => Some(format!("fn {name}({inputs}) -> {output}")),
+        Some(outputs) => Some(format!("fn {name}({inputs}) -> ({})", outputs.join(", "))),
+    }
+}
+
+fn render_type(ty: &Vlq, type_names: &[&str]) -> String {
+    let (id, generics) = match ty {
+        Vlq::Num(id) => (*id, &[][..]),
+        Vlq::List(parts) => match (parts.first(), parts.get(1)) {
+            (Some(Vlq::Num(id)), Some(Vlq::List(generics))) => (*id, generics.as_slice()),
+            (Some(Vlq::Num(id)), _) => (*id, &[][..]),
+            _ => (0, &[][..]),
+        },
+    };
+
+    let name = usize::try_from(id)
+        .ok()
+        .and_then(|id| type_names.get(id.checked_sub(1)?))
+        .copied()
+        .unwrap_or("_");
+    let generics = generics
+        .iter()
+        .map(|generic| render_type(generic, type_names))
+        .collect::<Vec<String>>();
+
+    match (name, generics.as_slice()) {
+        ("unit", _) => "()".to_owned(),
+        ("reference", [.., inner]) => format!("&{inner}"),
+        ("tuple", generics) => format!("({})", generics.join(", ")),
+        ("slice" | "array", [inner, ..]) => format!("[{inner}]"),
+        (name, []) => name.to_owned(),
+        (name, generics) => format!("{name}<{}>", generics.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    const SEARCH_INDEX_JS: &str = r#"var searchIndex = new Map(JSON.parse('[\
+["timer",{"t":"DLLFEN","n":["Timer","new","elapsed","sleep","Kind","Fast"],"q":[[0,"timer"],[3,"timer::time"],[4,"timer"]],"i":"`bb``d","f":"`{{}b}{bf}{j{{h{f}}}}``","D":"h","e":"OjAAAAEAAAAAAAIAEAAAAAMABQAGAA==","c":"OjAAAAEAAAAAAAAAEAAAAAYA","p":[[3,"Timer"],[4,"Kind"],[3,"Duration"],[4,"Result"],[3,"Instant"]],"b":[]}]\
+]'));
+if (typeof exports !== 'undefined') exports.searchIndex = searchIndex;
+"#;
+
+    const DESC_SHARD_JS: &str = r#"searchState.loadedDescShard("timer", 0, "Timers.\nA timer & its state.\nCreates a <code>Timer</code>.\nSleeps until the deadline.")"#;
+
+    #[test]
+    fn test_search_index_files() {
+        let files = super::SearchIndexFiles::from_html(
+            r#"<meta name="rustdoc-vars" data-root-path="../" data-resource-suffix="-20240517-1.80.0">"#,
+        );
+        assert_eq!(files.search_index(), "search-index-20240517-1.80.0.js");
+        assert_eq!(
+            files.desc_shard("timer", 1),
+            "search.desc/timer/timer-desc-1--20240517-1.80.0.js"
+        );
+
+        let files = super::SearchIndexFiles::from_html(
+            r#"<div id="rustdoc-vars" data-resource-suffix="/../x" data-search-index-js="../search-index1.65.0.js"></div>"#,
+        );
+        assert_eq!(files.search_index(), "search-index1.65.0.js");
+        assert_eq!(
+            files.desc_shard("timer", 0),
+            "search.desc/timer/timer-desc-0-.js"
+        );
+    }
+
+    #[test]
+    fn test_parse_items() -> Result<(), crate::error::Error> {
+        let corpus = super::parse_crate_corpus(SEARCH_INDEX_JS, "timer")?;
+        assert_eq!(super::desc_shard_count(&corpus), 1);
+
+        let descs = super::parse_desc_shard(DESC_SHARD_JS)?;
+        let items = super::parse_items("timer", &corpus, &descs)?;
+
+        let item = |path: &str| {
+            items
+                .iter()
+                .find(|item| item.path.as_deref() == Some(path))
+                .unwrap()
+        };
+
+        let timer = item("Timer");
+        assert_eq!(timer.href.as_deref(), Some("struct.Timer.html"));
+        assert_eq!(timer.summary.as_deref(), Some("A timer & its state."));
+
+        let new = item("Timer::new");
+        assert_eq!(new.r#type, "Methods");
+        assert_eq!(new.href.as_deref(), Some("struct.Timer.html#method.new"));
+        assert_eq!(new.summary.as_deref(), Some("Creates a Timer."));
+        assert_eq!(new.signature.as_deref(), Some("fn new() -> Timer"));
+
+        let elapsed = item("Timer::elapsed");
+        assert_eq!(elapsed.summary, None);
+        assert_eq!(
+            elapsed.signature.as_deref(),
+            Some("fn elapsed(Timer) -> Duration")
+        );
+
+        let sleep = item("time::sleep");
+        assert_eq!(sleep.href.as_deref(), Some("time/fn.sleep.html"));
+        assert_eq!(sleep.full_path.as_deref(), Some("timer::time::sleep"));
+        assert_eq!(
+            sleep.signature.as_deref(),
+            Some("fn sleep(Instant) -> Result<Duration>")
+        );
+        assert_eq!(sleep.summary.as_deref(), Some("Sleeps until the deadline."));
+
+        let fast = item("Kind::Fast");
+        assert_eq!(fast.href.as_deref(), Some("enum.Kind.html#variant.Fast"));
+        assert!(fast.deprecation.is_some());
+        assert!(item("Kind").deprecation.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_roaring_bitmap() {
+        let values = super::roaring_bitmap("OzAAAAEAAAIAAQADAAIA");
+        assert_eq!(values, [3, 4, 5].into_iter().collect());
+    }
+}
@@ -0,0 +1,694 @@
+// Type-signature search in the spirit of Hoogle and rustdoc's `A -> B` queries.
+// Signatures are read back from the rendered text of `Item::signature`, which both
+// rustdoc JSON and `search-index.js` produce, so every index source supports it.
+
+/// A type reduced to what matters for matching: the last path segment and the type
+/// arguments. References, pointers and lifetimes are dropped, `impl Trait` and
+/// `dyn Trait` become the trait, tuples are `()` and slices and arrays are `[]`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Ty {
+    pub name: String,
+    pub generics: Vec<Ty>,
+}
+
+impl Ty {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            generics: Vec::new(),
+        }
+    }
+
+    /// `_`, which matches any type.
+    fn wildcard() -> Self {
+        Self::new("_")
+    }
+
+    fn is_wildcard(&self) -> bool {
+        self.name == "_"
+    }
+
+    /// Replaces generic parameters by wildcards and `Self` by `self_ty`.
+    fn resolve(self, generic_params: &[String], self_ty: Option<&str>) -> Self {
+        let name = if generic_params.contains(&self.name) {
+            "_".to_owned()
+        } else if self.name == "Self" {
+            self_ty.unwrap_or("Self").to_owned()
+        } else {
+            self.name
+        };
+
+        Self {
+            name,
+            generics: self
+                .generics
+                .into_iter()
+                .map(|ty| ty.resolve(generic_params, self_ty))
+                .collect(),
+        }
+    }
+}
+
+/// Inputs and output of a function.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct FunctionType {
+    pub inputs: Vec<Ty>,
+    pub output: Option<Ty>,
+}
+
+/// A parsed `A, B -> C` query.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct SignatureQuery {
+    inputs: Vec<Ty>,
+    outputs: Vec<Ty>,
+    /// Without `->`, the types may appear among the inputs or as the output.
+    anywhere: bool,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let matches = token
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.chars.get(self.pos + i) == Some(&c));
+        if matches {
+            self.pos += token.chars().count();
+        }
+        matches
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let start = self.pos;
+        if self.ident().as_deref() == Some(keyword) {
+            return true;
+        }
+        self.pos = start;
+        false
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+        {
+            self.pos += 1;
+        }
+        (self.pos > start).then(|| self.chars[start..self.pos].iter().collect())
+    }
+
+    fn lifetime(&mut self) -> bool {
+        if self.peek() != Some('\'') {
+            return false;
+        }
+        self.pos += 1;
+        self.ident();
+        true
+    }
+
+    /// Skips a balanced `<...>`, `(...)` or `[...]` group starting at the current position.
+    fn skip_group(&mut self) {
+        let mut depth = 0usize;
+        while let Some(c) = self.chars.get(self.pos) {
+            self.pos += 1;
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' if self.chars.get(self.pos.wrapping_sub(2)) == Some(&'-') => {}
+                '>' | ')' | ']' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_type(&mut self) -> Ty {
+        match self.peek() {
+            Some('&') => {
+                self.pos += 1;
+                self.lifetime();
+                self.eat_keyword("mut");
+                self.parse_type()
+            }
+            Some('*') => {
+                self.pos += 1;
+                let _ = self.eat_keyword("const") || self.eat_keyword("mut");
+                self.parse_type()
+            }
+            Some('(') => {
+                self.pos += 1;
+                let mut elements = self.parse_list(')');
+                match elements.len() {
+                    1 => elements.remove(0),
+                    _ => Ty {
+                        name: "()".to_owned(),
+                        generics: elements,
+                    },
+                }
+            }
+            Some('[') => {
+                self.pos += 1;
+                let element = self.parse_type();
+                // Skip the `; N` of arrays.
+                while let Some(c) = self.chars.get(self.pos) {
+                    self.pos += 1;
+                    if *c == ']' {
+                        break;
+                    }
+                }
+                Ty {
+                    name: "[]".to_owned(),
+                    generics: vec![element],
+                }
+            }
+            Some('<') => {
+                // `<T as Trait>::Assoc`
+                self.skip_group();
+                let mut name = None;
+                while self.eat("::") {
+                    name = self.ident();
+                }
+                Ty::new(name.as_deref().unwrap_or("_"))
+            }
+            Some('!') => {
+                self.pos += 1;
+                Ty::new("!")
+            }
+            Some(_) => self.parse_path(),
+            None => Ty::wildcard(),
+        }
+    }
+
+    fn parse_path(&mut self) -> Ty {
+        let Some(mut name) = self.ident() else {
+            // Unknown syntax: consume one character so that parsing always makes progress.
+            self.pos += 1;
+            return Ty::wildcard();
+        };
+
+        match name.as_str() {
+            "impl" | "dyn" => {
+                if self.eat_keyword("for") {
+                    self.skip_group();
+                }
+                let mut bounds = Vec::new();
+                loop {
+                    if !self.lifetime() {
+                        let start = self.pos;
+                        let bound = self.parse_type();
+                        if self.pos == start {
+                            break;
+                        }
+                        bounds.push(bound);
+                    }
+                    if !self.eat("+") {
+                        break;
+                    }
+                }
+                return bounds.into_iter().next().unwrap_or_else(Ty::wildcard);
+            }
+            "fn" | "unsafe" | "extern" => {
+                while self.peek().is_some_and(|c| c != '(') && self.ident().is_some() {}
+                if self.peek() == Some('"') {
+                    self.pos += 1;
+                    while self.chars.get(self.pos).is_some_and(|c| *c != '"') {
+                        self.pos += 1;
+                    }
+                    self.pos += 1;
+                    while self.peek().is_some_and(|c| c != '(') && self.ident().is_some() {}
+                }
+                return self.parse_fn_sugar("fn");
+            }
+            _ => {}
+        }
+
+        while self.eat("::") {
+            match self.peek() {
+                Some('<') => break,
+                _ => match self.ident() {
+                    Some(segment) => name = segment,
+                    None => break,
+                },
+            }
+        }
+
+        if matches!(name.as_str(), "Fn" | "FnMut" | "FnOnce") && self.peek() == Some('(') {
+            return self.parse_fn_sugar(&name);
+        }
+
+        let generics = if self.peek() == Some('<') {
+            self.pos += 1;
+            self.parse_list('>')
+        } else {
+            Vec::new()
+        };
+
+        Ty { name, generics }
+    }
+
+    /// `fn(A) -> B` and `Fn(A) -> B`, represented with the inputs followed by the output.
+    fn parse_fn_sugar(&mut self, name: &str) -> Ty {
+        let mut generics = if self.eat("(") {
+            self.parse_list(')')
+        } else {
+            Vec::new()
+        };
+        if self.eat("->") {
+            generics.push(self.parse_type());
+        }
+
+        Ty {
+            name: name.to_owned(),
+            generics,
+        }
+    }
+
+    /// Comma-separated types up to `close`, skipping lifetimes and
+    /// reading the type of associated type bindings such as `Item = T`.
+    fn parse_list(&mut self, close: char) -> Vec<Ty> {
+        let mut types = Vec::new();
+
+        loop {
+            match self.peek() {
+                None => break,
+                Some(c) if c == close => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(',') => self.pos += 1,
+                Some('\'') => {
+                    self.lifetime();
+                }
+                Some(_) => {
+                    let start = self.pos;
+                    if !(self.ident().is_some() && self.eat("=")) {
+                        self.pos = start;
+                    }
+                    types.push(self.parse_type());
+                    if self.pos == start {
+                        self.pos += 1;
+                    }
+                }
+            }
+        }
+
+        types
+    }
+
+    /// Names declared by `<T, const N: usize, 'a>`.
+    fn parse_generic_params(&mut self) -> Vec<String> {
+        let mut params = Vec::new();
+        if self.peek() != Some('<') {
+            return params;
+        }
+
+        let start = self.pos;
+        self.skip_group();
+        let end = self.pos;
+
+        let mut depth = 0usize;
+        let mut at_param_start = true;
+        self.pos = start + 1;
+        while self.pos < end.saturating_sub(1) {
+            match self.chars[self.pos] {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => at_param_start = true,
+                c if at_param_start && (c.is_alphabetic() || c == '_') => {
+                    let name = self.ident().unwrap_or_default();
+                    if name == "const" {
+                        continue;
+                    }
+                    params.push(name);
+                    at_param_start = false;
+                    continue;
+                }
+                c if !c.is_whitespace() => at_param_start = false,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+
+        self.pos = end;
+        params
+    }
+}
+
+/// Reads the inputs and output of a rendered function signature, such as
+/// `pub fn parse<T: FromStr>(s: &str) -> Result<T, Error>` or `fn sleep(Instant) -> Result<Duration>`.
+///
+/// `self_ty` replaces `Self`, e.g. the type a method is implemented on.
+pub(super) fn parse_function(signature: &str, self_ty: Option<&str>) -> Option<FunctionType> {
+    let start = signature
+        .match_indices("fn ")
+        .find(|(i, _)| *i == 0 || signature[..*i].ends_with(' '))
+        .map(|(i, _)| i + "fn ".len())?;
+
+    let mut parser = Parser::new(&signature[start..]);
+    parser.ident()?;
+    let generic_params = parser.parse_generic_params();
+
+    if !parser.eat("(") {
+        return None;
+    }
+
+    let mut inputs = Vec::new();
+    loop {
+        match parser.peek() {
+            None => return None,
+            Some(')') => {
+                parser.pos += 1;
+                break;
+            }
+            Some(',') => parser.pos += 1,
+            Some(_) => {
+                let start = parser.pos;
+
+                // `self`, `&self`, `&'a mut self`, `self: Box<Self>` or `name: Type`.
+                let is_ref = parser.eat("&");
+                if is_ref {
+                    parser.lifetime();
+                    parser.eat_keyword("mut");
+                }
+
+                let ty = match parser.ident() {
+                    Some(name) if name == "self" && parser.peek() != Some(':') => Ty::new("Self"),
+                    Some(_)
+                        if !is_ref
+                            && parser.peek() == Some(':')
+                            && parser.chars.get(parser.pos + 1) != Some(&':') =>
+                    {
+                        parser.pos += 1;
+                        parser.parse_type()
+                    }
+                    _ => {
+                        parser.pos = start;
+                        parser.parse_type()
+                    }
+                };
+
+                inputs.push(ty.resolve(&generic_params, self_ty));
+
+                if parser.pos == start {
+                    parser.pos += 1;
+                }
+            }
+        }
+    }
+
+    let output = parser
+        .eat("->")
+        .then(|| parser.parse_type().resolve(&generic_params, self_ty));
+
+    Some(FunctionType { inputs, output })
+}
+
+/// Parses `A, B -> C`, `-> C`, `A ->` or just `A`. `_` and single capital letters
+/// such as `T` stand for any type.
+pub(super) fn parse_query(query: &str) -> SignatureQuery {
+    let types = |text: &str| {
+        let mut parser = Parser::new(text);
+        parser
+            .parse_list('\0')
+            .into_iter()
+            .map(|ty| {
+                let is_generic =
+                    ty.name.len() == 1 && ty.name.chars().all(|c| c.is_ascii_uppercase());
+                if is_generic { Ty::wildcard() } else { ty }
+            })
+            .collect::<Vec<Ty>>()
+    };
+
+    match split_arrow(query) {
+        Some((inputs, outputs)) => SignatureQuery {
+            inputs: types(inputs),
+            outputs: types(outputs),
+            anywhere: false,
+        },
+        None => SignatureQuery {
+            inputs: types(query),
+            outputs: Vec::new(),
+            anywhere: true,
+        },
+    }
+}
+
+/// Splits at the first `->` outside of type arguments, so that `Fn(A) -> B` stays intact.
+fn split_arrow(query: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+
+    for (i, c) in query.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            '>' if query[..i].ends_with('-') && depth == 0 => {
+                return Some((&query[..i - 1], &query[i + 1..]));
+            }
+            '>' if query[..i].ends_with('-') => {}
+            '>' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+impl SignatureQuery {
+    pub(super) fn is_empty(&self) -> bool {
+        self.inputs.is_empty() && self.outputs.is_empty()
+    }
+
+    /// How closely a function matches, from 0 (no match) to 1 (exact match).
+    pub(super) fn score(&self, function: &FunctionType) -> f32 {
+        let output = function.output.iter().cloned().collect::<Vec<Ty>>();
+
+        let (score, matched) = if self.anywhere {
+            let candidates = function
+                .inputs
+                .iter()
+                .chain(&output)
+                .cloned()
+                .collect::<Vec<Ty>>();
+            match match_list(&self.inputs, &candidates) {
+                Some((score, _)) => (score, self.inputs.len()),
+                None => return 0.0,
+            }
+        } else {
+            let Some((input_score, inputs)) = match_list(&self.inputs, &function.inputs) else {
+                return 0.0;
+            };
+            // Several output types are matched against the elements of a returned tuple.
+            let output = match (self.outputs.len(), function.output.as_ref()) {
+                (2.., Some(ty)) if ty.name == "()" => ty.generics.clone(),
+                _ => output,
+            };
+            let Some((output_score, _)) = match_list(&self.outputs, &output) else {
+                return 0.0;
+            };
+            (input_score + output_score, inputs)
+        };
+
+        let total = self.inputs.len() + self.outputs.len();
+        let score = if total == 0 {
+            0.0
+        } else {
+            score / total as f32
+        };
+
+        // Prefer functions without unrelated extra arguments.
+        let extra = function.inputs.len().saturating_sub(matched);
+        score / (1.0 + 0.1 * extra as f32)
+    }
+}
+
+/// Greedily matches every query type to a distinct candidate. Returns the sum of the
+/// scores and the number of matched candidates, or `None` when a query type has no match.
+fn match_list(queries: &[Ty], candidates: &[Ty]) -> Option<(f32, usize)> {
+    let mut used = vec![false; candidates.len()];
+    let mut total = 0.0;
+
+    for query in queries {
+        let (index, score) = candidates
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used[*i])
+            .map(|(i, candidate)| (i, type_score(query, candidate)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+        if score <= 0.0 {
+            return None;
+        }
+
+        used[index] = true;
+        total += score;
+    }
+
+    Some((total, queries.len()))
+}
+
+fn type_score(query: &Ty, candidate: &Ty) -> f32 {
+    if query.is_wildcard() {
+        return 0.8;
+    }
+    if candidate.is_wildcard() {
+        // A generic parameter may be instantiated with the queried type.
+        return 0.4;
+    }
+
+    if query.name.eq_ignore_ascii_case(&candidate.name) {
+        if query.generics.is_empty() {
+            return if candidate.generics.is_empty() {
+                1.0
+            } else {
+                0.9
+            };
+        }
+
+        return match match_list(&query.generics, &candidate.generics) {
+            Some((score, _)) => 0.5 + 0.5 * score / query.generics.len() as f32,
+            None => 0.3,
+        };
+    }
+
+    // The queried type wrapped in another one, e.g. `Duration` in `Result<Duration>`.
+    candidate
+        .generics
+        .iter()
+        .map(|generic| type_score(query, generic) * 0.6)
+        .fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod test {
+    fn ty(name: &str, generics: Vec<super::Ty>) -> super::Ty {
+        super::Ty {
+            name: name.to_owned(),
+            generics,
+        }
+    }
+
+    #[test]
+    fn test_parse_function() {
+        let function = super::parse_function(
+            "pub fn from_naive_utc_and_offset<'a, T: Into<String>>(datetime: &'a NaiveDateTime, offset: T, f: impl Fn(u8) -> bool) -> chrono::DateTime<Utc> where T: Clone",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            function.inputs,
+            vec![
+                ty("NaiveDateTime", vec![]),
+                ty("_", vec![]),
+                ty("Fn", vec![ty("u8", vec![]), ty("bool", vec![])]),
+            ]
+        );
+        assert_eq!(
+            function.output,
+            Some(ty("DateTime", vec![ty("Utc", vec![])]))
+        );
+
+        let function = super::parse_function(
+            "pub fn and_hms(&self, hour: u32) -> Self",
+            Some("NaiveDate"),
+        )
+        .unwrap();
+        assert_eq!(
+            function.inputs,
+            vec![ty("NaiveDate", vec![]), ty("u32", vec![])]
+        );
+        assert_eq!(function.output, Some(ty("NaiveDate", vec![])));
+
+        let function =
+            super::parse_function("fn sleep(Instant) -> Result<Duration>", None).unwrap();
+        assert_eq!(function.inputs, vec![ty("Instant", vec![])]);
+
+        let function = super::parse_function(
+            "pub fn iter(&self) -> impl Iterator<Item = (&str, [u8; 4])> + '_",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            function.output,
+            Some(ty(
+                "Iterator",
+                vec![ty(
+                    "()",
+                    vec![ty("str", vec![]), ty("[]", vec![ty("u8", vec![])])]
+                )]
+            ))
+        );
+
+        assert!(super::parse_function("pub struct Foo", None).is_none());
+    }
+
+    #[test]
+    fn test_score() {
+        let score = |query: &str, signature: &str| {
+            let function = super::parse_function(signature, Some("NaiveDate")).unwrap();
+            super::parse_query(query).score(&function)
+        };
+
+        let exact = score(
+            "NaiveDate -> DateTime<Utc>",
+            "fn to_utc(self) -> DateTime<Utc>",
+        );
+        let other_tz = score(
+            "NaiveDate -> DateTime<Utc>",
+            "fn to_local(self) -> DateTime<Local>",
+        );
+        let extra_input = score(
+            "NaiveDate -> DateTime<Utc>",
+            "fn to_utc_at(self, time: NaiveTime) -> DateTime<Utc>",
+        );
+        let generic = score(
+            "NaiveDate -> DateTime<Utc>",
+            "fn to_tz<Tz: TimeZone>(self) -> DateTime<Tz>",
+        );
+        let wrapped = score(
+            "NaiveDate -> DateTime<Utc>",
+            "fn try_to_utc(self) -> Option<DateTime<Utc>>",
+        );
+
+        assert_eq!(exact, 1.0);
+        assert!(exact > generic && generic > other_tz);
+        assert!(exact > extra_input && extra_input > 0.0);
+        assert!(exact > wrapped && wrapped > 0.0);
+
+        assert_eq!(
+            score("NaiveDate -> String", "fn to_utc(self) -> DateTime<Utc>"),
+            0.0
+        );
+        assert!(score("-> Duration", "fn elapsed(&self) -> Duration") > 0.0);
+        assert!(score("T -> Option<T>", "fn first(&self) -> Option<u8>") > 0.0);
+        assert!(score("NaiveTime", "fn with(self, time: NaiveTime) -> Self") > 0.0);
+    }
+}
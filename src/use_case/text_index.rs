@@ -0,0 +1,258 @@
+/// Prose, headings and code examples of one documentation page, as indexed by [`TextIndex`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageText {
+    /// Path of the documented item without the crate name, or the crate name for its root page.
+    pub path: String,
+    /// Page relative to the crate's documentation root, e.g. `/de/trait.Deserialize.html`.
+    pub href: String,
+    pub headings: Vec<String>,
+    pub docs: String,
+    pub examples: Vec<String>,
+}
+
+/// Tantivy full-text index over the documentation pages of one crate version.
+pub struct TextIndex {
+    index: tantivy::Index,
+    reader: tantivy::IndexReader,
+}
+
+impl std::fmt::Debug for TextIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextIndex")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TextIndex {
+    /// Length of the snippets returned with each hit.
+    const SNIPPET_LENGTH: usize = 240;
+
+    fn schema() -> tantivy::schema::Schema {
+        // Prose is stemmed so that `shutting down` also finds `shutdown` and `shuts down`.
+        let prose = tantivy::schema::TextOptions::default()
+            .set_indexing_options(
+                tantivy::schema::TextFieldIndexing::default()
+                    .set_tokenizer("en_stem")
+                    .set_index_option(tantivy::schema::IndexRecordOption::WithFreqsAndPositions),
+            )
+            .set_stored();
+
+        let mut schema_builder = tantivy::schema::Schema::builder();
+        schema_builder.add_text_field("path", tantivy::schema::TEXT | tantivy::schema::STORED);
+        schema_builder.add_text_field("href", tantivy::schema::STORED);
+        schema_builder.add_text_field("headings", prose.clone());
+        schema_builder.add_text_field("docs", prose);
+        schema_builder.add_text_field("examples", tantivy::schema::TEXT | tantivy::schema::STORED);
+        schema_builder.build()
+    }
+
+    fn from_index(
+        index: tantivy::Index,
+        pages: Option<&[PageText]>,
+    ) -> Result<Self, crate::error::Error> {
+        if let Some(pages) = pages {
+            let schema = index.schema();
+            let path_field = schema.get_field("path")?;
+            let href_field = schema.get_field("href")?;
+            let headings_field = schema.get_field("headings")?;
+            let docs_field = schema.get_field("docs")?;
+            let examples_field = schema.get_field("examples")?;
+
+            let mut index_writer: tantivy::IndexWriter = index.writer(50_000_000)?;
+
+            for page in pages {
+                let mut doc = tantivy::TantivyDocument::default();
+                doc.add_text(path_field, &page.path);
+                doc.add_text(href_field, &page.href);
+                doc.add_text(headings_field, page.headings.join("\n"));
+                doc.add_text(docs_field, &page.docs);
+                doc.add_text(examples_field, page.examples.join("\n\n"));
+                index_writer.add_document(doc)?;
+            }
+
+            index_writer.commit()?;
+        }
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(tantivy::ReloadPolicy::Manual)
+            .try_into()?;
+
+        Ok(Self { index, reader })
+    }
+
+    /// Ranks pages against `query`, with a snippet of the best matching passage in which
+    /// the matched words are highlighted in bold.
+    pub fn search(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::entity::docs::TextSearchHit>, crate::error::Error> {
+        use tantivy::schema::Value;
+
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let schema = self.index.schema();
+        let path_field = schema.get_field("path")?;
+        let href_field = schema.get_field("href")?;
+        let headings_field = schema.get_field("headings")?;
+        let docs_field = schema.get_field("docs")?;
+        let examples_field = schema.get_field("examples")?;
+
+        let mut query_parser = tantivy::query::QueryParser::for_index(
+            &self.index,
+            vec![path_field, headings_field, docs_field, examples_field],
+        );
+        query_parser.set_field_boost(path_field, 2.0);
+        query_parser.set_field_boost(headings_field, 1.5);
+        query_parser.set_field_boost(examples_field, 0.5);
+
+        let (query, _errors) = query_parser.parse_query_lenient(query);
+        let searcher = self.reader.searcher();
+
+        let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(limit))?;
+
+        let mut snippet_generators = [docs_field, headings_field, examples_field]
+            .into_iter()
+            .map(|field| {
+                let mut generator =
+                    tantivy::snippet::SnippetGenerator::create(&searcher, &query, field)?;
+                generator.set_max_num_chars(Self::SNIPPET_LENGTH);
+                Ok(generator)
+            })
+            .collect::<Result<Vec<tantivy::snippet::SnippetGenerator>, tantivy::TantivyError>>()?;
+
+        let mut hits = Vec::new();
+
+        for (score, doc_address) in top_docs {
+            let doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+
+            let text = |field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_owned()
+            };
+
+            let snippet = snippet_generators
+                .iter_mut()
+                .find_map(|generator| highlight(&generator.snippet_from_doc(&doc)));
+
+            hits.push(crate::entity::docs::TextSearchHit {
+                score,
+                path: text(path_field),
+                href: text(href_field),
+                snippet,
+            });
+        }
+
+        Ok(hits)
+    }
+}
+
+impl super::search_index::CachedIndex for TextIndex {
+    type Source = Vec<PageText>;
+
+    const SCHEMA_VERSION: &'static str = "text-v1";
+
+    fn create_in_ram(pages: &Self::Source) -> Result<Self, crate::error::Error> {
+        let index = tantivy::Index::create_in_ram(Self::schema());
+        Self::from_index(index, Some(pages))
+    }
+
+    fn create_in_dir(
+        path: &std::path::Path,
+        pages: &Self::Source,
+    ) -> Result<Self, crate::error::Error> {
+        super::search_index::persist_index(path, Self::schema(), |index| {
+            Self::from_index(index, Some(pages)).map(drop)
+        })?;
+
+        Self::open_in_dir(path)
+    }
+
+    fn open_in_dir(path: &std::path::Path) -> Result<Self, crate::error::Error> {
+        let index = tantivy::Index::open_in_dir(path)?;
+        Self::from_index(index, None)
+    }
+}
+
+/// Renders a snippet as a single line of markdown with the matched words in bold.
+fn highlight(snippet: &tantivy::snippet::Snippet) -> Option<String> {
+    let fragment = snippet.fragment();
+    if snippet.highlighted().is_empty() {
+        return None;
+    }
+
+    let mut text = String::new();
+    let mut last = 0;
+    for range in snippet.highlighted() {
+        text.push_str(&fragment[last..range.start]);
+        text.push_str("**");
+        text.push_str(&fragment[range.clone()]);
+        text.push_str("**");
+        last = range.end;
+    }
+    text.push_str(&fragment[last..]);
+
+    Some(text.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::search_index::CachedIndex;
+
+    #[test]
+    fn test_search() -> Result<(), crate::error::Error> {
+        let pages = vec![
+            super::PageText {
+                path: "sync::mpsc".to_owned(),
+                href: "/sync/mpsc/index.html".to_owned(),
+                headings: vec!["Backpressure".to_owned()],
+                docs: "A bounded channel applies backpressure: senders wait until the receiver catches up.".to_owned(),
+                examples: vec!["let (tx, rx) = mpsc::channel(32);".to_owned()],
+            },
+            super::PageText {
+                path: "runtime::Runtime".to_owned(),
+                href: "/runtime/struct.Runtime.html".to_owned(),
+                headings: vec!["Shutdown".to_owned()],
+                docs: "Dropping the runtime shuts down all spawned tasks gracefully.".to_owned(),
+                examples: Vec::new(),
+            },
+        ];
+
+        let index = super::TextIndex::create_in_ram(&pages)?;
+
+        let hits = index.search("backpressure", 10)?;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].href, "/sync/mpsc/index.html");
+        assert!(
+            hits[0]
+                .snippet
+                .as_deref()
+                .unwrap()
+                .contains("applies **backpressure**")
+        );
+
+        let hits = index.search("graceful shutdown", 10)?;
+        assert_eq!(hits[0].path, "runtime::Runtime");
+        assert!(
+            hits[0]
+                .snippet
+                .as_deref()
+                .unwrap()
+                .contains("**gracefully**")
+        );
+
+        let hits = index.search("channel(32)", 10)?;
+        assert_eq!(hits[0].path, "sync::mpsc");
+
+        // Tantivy panics on a limit of 0.
+        assert!(index.search("backpressure", 0)?.is_empty());
+
+        Ok(())
+    }
+}
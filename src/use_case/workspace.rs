@@ -0,0 +1,505 @@
+/// Dependencies parsed from a lockfile or manifest, keyed by its path and modification time.
+pub type DependencyCache = std::sync::Arc<
+    std::sync::Mutex<
+        Option<(
+            std::path::PathBuf,
+            std::time::SystemTime,
+            Vec<crate::entity::workspace::WorkspaceDependency>,
+        )>,
+    >,
+>;
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceUseCase {
+    pub file_repository: std::sync::Arc<dyn crate::repository::file::FileRepository + Send + Sync>,
+    pub workspace: crate::config::WorkspaceConfig,
+    pub dependency_cache: DependencyCache,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    /// Missing for workspace members and other path dependencies.
+    source: Option<String>,
+    /// Entries are `name`, `name version` or `name version (source)`, depending on
+    /// whether the name alone is unambiguous within the lockfile.
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// Dependency tables of a manifest, including target-specific ones.
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+impl WorkspaceUseCase {
+    fn dir(&self) -> Result<&std::path::Path, crate::error::Error> {
+        self.workspace.dir.as_deref().ok_or_else(|| {
+            crate::error::Error::Workspace(
+                "No workspace configured. Start the server with `--workspace <path>` or set `MCP_RUST_DOCS_WORKSPACE`".to_owned(),
+            )
+        })
+    }
+
+    /// Lists the direct and transitive dependencies of the workspace with their locked versions,
+    /// sorted by name and version. Without a `Cargo.lock`, only the direct dependencies declared
+    /// in `Cargo.toml` are known, with their version requirements.
+    ///
+    /// The result is reused until the lockfile (or root manifest) is modified; changes to the
+    /// manifests of members of a workspace without a lockfile are not noticed.
+    pub async fn dependencies(
+        &self,
+    ) -> Result<Vec<crate::entity::workspace::WorkspaceDependency>, crate::error::Error> {
+        let dir = self.dir()?;
+
+        // Members of a larger workspace share the lockfile of the workspace root.
+        let mut lockfile = None;
+        for ancestor in dir.ancestors() {
+            let path = ancestor.join("Cargo.lock");
+            if let Some(modified) = self.file_repository.modified(&path).await {
+                lockfile = Some((path, modified));
+                break;
+            }
+        }
+
+        let (path, modified) = match lockfile {
+            Some(lockfile) => lockfile,
+            None => {
+                let path = dir.join("Cargo.toml");
+                let modified = self.file_repository.modified(&path).await.ok_or_else(|| {
+                    crate::error::Error::Workspace(format!(
+                        "Neither Cargo.lock nor {} found",
+                        path.display()
+                    ))
+                })?;
+                (path, modified)
+            }
+        };
+
+        if let Some((cached_path, cached_modified, dependencies)) =
+            self.dependency_cache.lock().unwrap().as_ref()
+            && *cached_path == path
+            && *cached_modified == modified
+        {
+            return Ok(dependencies.clone());
+        }
+
+        let content = self
+            .file_repository
+            .read_to_string(&path)
+            .await
+            .map_err(|e| crate::error::Error::Workspace(e.to_string()))?;
+
+        let dependencies = if path.ends_with("Cargo.lock") {
+            parse_lockfile(&content)?
+        } else {
+            self.manifest_dependencies(dir, &content).await?
+        };
+
+        *self.dependency_cache.lock().unwrap() = Some((path, modified, dependencies.clone()));
+
+        Ok(dependencies)
+    }
+
+    async fn manifest_dependencies(
+        &self,
+        dir: &std::path::Path,
+        manifest: &str,
+    ) -> Result<Vec<crate::entity::workspace::WorkspaceDependency>, crate::error::Error> {
+        let (mut dependencies, members) = parse_manifest(manifest)?;
+
+        for member in members {
+            let path = dir.join(&member).join("Cargo.toml");
+            if self.file_repository.modified(&path).await.is_none() {
+                tracing::warn!(
+                    "Skipping workspace member {member}: {} not found",
+                    path.display()
+                );
+                continue;
+            }
+
+            let manifest = self
+                .file_repository
+                .read_to_string(&path)
+                .await
+                .map_err(|e| crate::error::Error::Workspace(e.to_string()))?;
+            dependencies.extend(parse_manifest(&manifest)?.0);
+        }
+
+        dependencies.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        dependencies.dedup();
+
+        Ok(dependencies)
+    }
+
+    /// Version of `crate_name` the workspace builds with, or `None` when no workspace is
+    /// configured or the crate is not one of its dependencies. When several versions are
+    /// locked, the one the workspace depends on directly wins, then the newest.
+    pub async fn locked_version(
+        &self,
+        crate_name: &str,
+    ) -> Result<Option<String>, crate::error::Error> {
+        if self.workspace.dir.is_none() {
+            return Ok(None);
+        }
+
+        let normalize = |name: &str| name.replace('-', "_");
+        let crate_name = normalize(crate_name);
+
+        let version = self
+            .dependencies()
+            .await?
+            .into_iter()
+            .filter(|dependency| normalize(&dependency.name) == crate_name)
+            .max_by(|a, b| {
+                let version = |dependency: &crate::entity::workspace::WorkspaceDependency| {
+                    semver::Version::parse(&dependency.version).ok()
+                };
+                (a.direct, version(a)).cmp(&(b.direct, version(b)))
+            })
+            .map(|dependency| dependency.version);
+
+        Ok(version)
+    }
+}
+
+fn parse_lockfile(
+    lockfile: &str,
+) -> Result<Vec<crate::entity::workspace::WorkspaceDependency>, crate::error::Error> {
+    let Lockfile { package: packages } = toml::from_str(lockfile)
+        .map_err(|e| crate::error::Error::Workspace(format!("Invalid Cargo.lock: {e}")))?;
+
+    // Packages without a source are the workspace members and their path dependencies.
+    let direct = packages
+        .iter()
+        .filter(|package| package.source.is_none())
+        .flat_map(|package| &package.dependencies)
+        .map(|dependency| {
+            let mut parts = dependency.split_whitespace();
+            (parts.next().unwrap_or_default(), parts.next())
+        })
+        .collect::<Vec<(&str, Option<&str>)>>();
+
+    let mut dependencies = packages
+        .iter()
+        .filter(|package| package.source.is_some())
+        .map(|package| crate::entity::workspace::WorkspaceDependency {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            locked: true,
+            direct: direct.iter().any(|(name, version)| {
+                *name == package.name && version.is_none_or(|version| version == package.version)
+            }),
+            source: package.source.clone(),
+        })
+        .collect::<Vec<crate::entity::workspace::WorkspaceDependency>>();
+
+    dependencies.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    Ok(dependencies)
+}
+
+/// Reads the registry and git dependencies of a manifest, and the workspace members it lists.
+/// Member globs such as `crates/*` are not expanded.
+fn parse_manifest(
+    manifest: &str,
+) -> Result<
+    (
+        Vec<crate::entity::workspace::WorkspaceDependency>,
+        Vec<String>,
+    ),
+    crate::error::Error,
+> {
+    let manifest = manifest
+        .parse::<toml::Table>()
+        .map_err(|e| crate::error::Error::Workspace(format!("Invalid Cargo.toml: {e}")))?;
+
+    let workspace = manifest.get("workspace").and_then(toml::Value::as_table);
+
+    let targets = manifest
+        .get("target")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|targets| targets.values())
+        .filter_map(toml::Value::as_table);
+
+    let tables = std::iter::once(&manifest)
+        .chain(targets)
+        .flat_map(|table| DEPENDENCY_TABLES.iter().filter_map(|key| table.get(*key)))
+        .chain(workspace.and_then(|workspace| workspace.get("dependencies")))
+        .filter_map(toml::Value::as_table);
+
+    let mut dependencies = Vec::new();
+
+    for table in tables {
+        for (key, value) in table {
+            let dependency = match value {
+                toml::Value::String(version) => Some((key.as_str(), version.as_str(), None)),
+                toml::Value::Table(detail) => {
+                    let name = detail
+                        .get("package")
+                        .and_then(toml::Value::as_str)
+                        .unwrap_or(key);
+                    let git = detail
+                        .get("git")
+                        .and_then(toml::Value::as_str)
+                        .map(|git| format!("git+{git}"));
+
+                    // `workspace = true` entries are read from `[workspace.dependencies]`, and
+                    // path dependencies are part of the workspace itself.
+                    detail
+                        .get("version")
+                        .and_then(toml::Value::as_str)
+                        .filter(|_| !detail.contains_key("path"))
+                        .map(|version| (name, version, git))
+                }
+                _ => None,
+            };
+
+            if let Some((name, version, source)) = dependency {
+                dependencies.push(crate::entity::workspace::WorkspaceDependency {
+                    name: name.to_owned(),
+                    version: version.to_owned(),
+                    locked: false,
+                    direct: true,
+                    source,
+                });
+            }
+        }
+    }
+
+    let members = workspace
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_str)
+        .filter(|member| !member.contains('*'))
+        .map(str::to_owned)
+        .collect();
+
+    Ok((dependencies, members))
+}
+
+#[cfg(test)]
+mod test {
+    fn use_case(dir: &std::path::Path) -> super::WorkspaceUseCase {
+        super::WorkspaceUseCase {
+            file_repository: std::sync::Arc::new(crate::repository::file::FileRepositoryImpl {}),
+            workspace: crate::config::WorkspaceConfig {
+                dir: Some(dir.to_owned()),
+            },
+            dependency_cache: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lockfile() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let member = dir.path().join("app");
+        std::fs::create_dir(&member).unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.lock"),
+            r#"
+version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "syn 2.0.87",
+ "tokio",
+]
+
+[[package]]
+name = "proc-macro2"
+version = "1.0.89"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "syn"
+version = "1.0.109"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "syn"
+version = "2.0.87"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "proc-macro2",
+]
+
+[[package]]
+name = "tokio"
+version = "1.38.2"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "syn 1.0.109",
+]
+"#,
+        )
+        .unwrap();
+
+        // A member directory finds the lockfile of the workspace root.
+        let use_case = use_case(&member);
+
+        let dependencies = use_case.dependencies().await?;
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(|d| (d.name.as_str(), d.version.as_str(), d.direct))
+                .collect::<Vec<_>>(),
+            vec![
+                ("proc-macro2", "1.0.89", false),
+                ("syn", "1.0.109", false),
+                ("syn", "2.0.87", true),
+                ("tokio", "1.38.2", true),
+            ]
+        );
+        assert!(dependencies.iter().all(|d| d.locked));
+
+        assert_eq!(
+            use_case.locked_version("tokio").await?.as_deref(),
+            Some("1.38.2")
+        );
+        assert_eq!(
+            use_case.locked_version("syn").await?.as_deref(),
+            Some("2.0.87")
+        );
+        assert_eq!(
+            use_case.locked_version("proc_macro2").await?.as_deref(),
+            Some("1.0.89")
+        );
+        assert_eq!(use_case.locked_version("serde").await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dependency_cache() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        let lockfile = dir.path().join("Cargo.lock");
+        let write = |version: &str, modified: std::time::SystemTime| {
+            std::fs::write(
+                &lockfile,
+                format!(
+                    r#"
+version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "tokio",
+]
+
+[[package]]
+name = "tokio"
+version = "{version}"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#
+                ),
+            )
+            .unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&lockfile)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        };
+
+        let modified =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        write("1.38.2", modified);
+
+        let use_case = use_case(dir.path());
+        assert_eq!(
+            use_case.locked_version("tokio").await?.as_deref(),
+            Some("1.38.2")
+        );
+
+        // The cached dependencies are kept while the lockfile's modification time is unchanged...
+        write("1.40.0", modified);
+        assert_eq!(
+            use_case.locked_version("tokio").await?.as_deref(),
+            Some("1.38.2")
+        );
+
+        // ...and re-parsed once it changes.
+        write("1.40.0", modified + std::time::Duration::from_secs(1));
+        assert_eq!(
+            use_case.locked_version("tokio").await?.as_deref(),
+            Some("1.40.0")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_manifest() -> Result<(), crate::error::Error> {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["app", "crates/*"]
+
+[workspace.dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("app")).unwrap();
+        std::fs::write(
+            dir.path().join("app/Cargo.toml"),
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+tokio = "1.38"
+serde = { workspace = true }
+common = { path = "../common", version = "0.1" }
+http02 = { package = "http", version = "0.2" }
+
+[target.'cfg(unix)'.dependencies]
+nix = { git = "https://github.com/nix-rust/nix", version = "0.29" }
+"#,
+        )
+        .unwrap();
+
+        let use_case = use_case(dir.path());
+
+        let dependencies = use_case.dependencies().await?;
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(|d| (d.name.as_str(), d.version.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("http", "0.2"),
+                ("nix", "0.29"),
+                ("serde", "1.0"),
+                ("tokio", "1.38")
+            ]
+        );
+        assert!(dependencies.iter().all(|d| d.direct && !d.locked));
+        assert_eq!(
+            dependencies[1].source.as_deref(),
+            Some("git+https://github.com/nix-rust/nix")
+        );
+
+        assert_eq!(
+            use_case.locked_version("tokio").await?.as_deref(),
+            Some("1.38")
+        );
+
+        Ok(())
+    }
+}